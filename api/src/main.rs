@@ -3,22 +3,34 @@ use std::fmt;
 
 use std::io::{prelude::*, BufReader};
 use std::net::{TcpListener, TcpStream};
-use words;
+
+use engine::{Game, Status};
+use words::score::Score;
+use words::share::{ShareGrid, Theme};
+use words::{daily, Date, WordList};
 
 type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
 
 fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let word_list = match (args.next(), args.next()) {
+        (None, _) => WordList::default(),
+        (Some(flag), Some(path)) if flag == "--words" => WordList::from_path(&path)
+            .map_err(|e| Error(format!("{path}: {e}")))?,
+        _ => return Err(Error("usage: api [--words <path>]".into()).into()),
+    };
+
     let listener = TcpListener::bind(("127.0.0.1", 7878))?;
 
     for stream in listener.incoming() {
         let stream = stream?;
-        handle(stream)?;
+        handle(stream, &word_list)?;
     }
 
     Ok(())
 }
 
-fn handle(mut stream: TcpStream) -> Result<()> {
+fn handle(mut stream: TcpStream, word_list: &WordList) -> Result<()> {
     let reader = BufReader::new(&mut stream);
     let lines: Vec<_> = reader
         .lines()
@@ -35,25 +47,33 @@ fn handle(mut stream: TcpStream) -> Result<()> {
         return Err("no request line".into());
     };
 
-    let request = parse_request_line(&request_line)?;
-    match request.method {
-        Method::Options => {
-            handle_cors(&mut stream)?;
-            return Ok(());
-        }
-        _ => {}
+    let request = parse_request_line(request_line)?;
+    if let Method::Options = request.method {
+        handle_cors(&mut stream)?;
+        return Ok(());
     }
     match &*request.resource {
-        "/word" => handle_word(&mut stream)?,
+        route if route == "/word" || route.starts_with("/word?") => {
+            handle_word(&mut stream, route, word_list)?;
+        }
+        route if route == "/daily" || route.starts_with("/daily?") => {
+            handle_daily(&mut stream, route, word_list)?;
+        }
+        route if route.starts_with("/play?") => {
+            handle_play(&mut stream, route, word_list)?;
+        }
+        route if route.starts_with("/share?") => {
+            handle_share(&mut stream, route)?;
+        }
         route if route.starts_with("/words?check=") => {
-            handle_check(&mut stream, route)?;
+            handle_check(&mut stream, route, word_list)?;
         }
         _ => handle_not_found(&mut stream)?,
     }
     Ok(())
 }
 
-fn handle_check(stream: &mut TcpStream, route: &str) -> Result<()> {
+fn handle_check(stream: &mut TcpStream, route: &str, word_list: &WordList) -> Result<()> {
     let Some(word) = route.rsplit("=").next() else {
         write!(stream, "HTTP/1.1 400 Bad Request\r\n")?;
         return Ok(());
@@ -64,7 +84,7 @@ fn handle_check(stream: &mut TcpStream, route: &str) -> Result<()> {
         return Err(Error("invalid word format".into()).into());
     };
 
-    let ok = words::check(word);
+    let ok = word_list.contains(word) || words::check(word);
 
     let json = format!("{{ \"ok\": \"{ok}\" }}");
 
@@ -77,6 +97,186 @@ fn handle_check(stream: &mut TcpStream, route: &str) -> Result<()> {
     Ok(())
 }
 
+/// Serves the daily puzzle. Defaults to today's, `?date=YYYY-MM-DD` or `?puzzle=N` select an
+/// earlier one. `turns=N` sets the turn limit, see [`turn_limit`].
+fn handle_daily(stream: &mut TcpStream, route: &str, word_list: &WordList) -> Result<()> {
+    let mut puzzle = daily::puzzle_number(Date::today());
+    for (key, value) in query(route) {
+        puzzle = match key {
+            "date" => value.parse().ok().and_then(daily::puzzle_number),
+            "puzzle" => value.parse().ok(),
+            // other parameters, such as cache busters, are ignored
+            _ => continue,
+        };
+    }
+    let (Some(puzzle), Some(turns)) = (puzzle, turn_limit(route)) else {
+        write!(stream, "HTTP/1.1 400 Bad Request\r\n")?;
+        return Ok(());
+    };
+
+    let word = word_list.daily(puzzle);
+    let word = words::to_str(&word);
+    let date = daily::puzzle_date(puzzle);
+    let json = format!(
+        "{{ \"puzzle\": {puzzle}, \"date\": \"{date}\", \"value\": \"{word}\", \"turns\": {turns} }}"
+    );
+
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\nContent-Type: application/json\r\nContent-Length: {length}\r\n\r\n{json}",
+        length = json.len()
+    )?;
+
+    Ok(())
+}
+
+/// Plays the guesses of a daily puzzle and serves their scores, so clients don't need to know the
+/// word or the rules.
+///
+/// `guesses` holds the comma-separated guesses, e.g. `?guesses=CRANE,FOCAL`. Optionally
+/// `puzzle=N` selects the puzzle instead of today's, `hard=true` enables hard mode and `turns=N`
+/// sets the turn limit. The word is only included once the game is over. A guess that isn't
+/// allowed is answered with `400 Bad Request` and the reason.
+fn handle_play(stream: &mut TcpStream, route: &str, word_list: &WordList) -> Result<()> {
+    let mut puzzle = daily::puzzle_number(Date::today());
+    let mut guesses = None;
+    let mut hard = false;
+    let mut valid = true;
+    for (key, value) in query(route) {
+        match key {
+            "puzzle" => puzzle = value.parse().ok(),
+            "guesses" => {
+                guesses = value
+                    .split(',')
+                    .filter(|guess| !guess.is_empty())
+                    // only letters, so they are safe to hash and to quote in the error
+                    .map(|guess| {
+                        let guess = guess.to_ascii_uppercase();
+                        let letters = guess.bytes().all(|c| c.is_ascii_uppercase());
+                        letters.then(|| guess.as_bytes().try_into().ok())?
+                    })
+                    .collect::<Option<Vec<_>>>();
+            }
+            "hard" => hard = value == "true",
+            "turns" => {}
+            _ => valid = false,
+        }
+    }
+    let (true, Some(puzzle), Some(guesses), Some(turn_limit)) =
+        (valid, puzzle, guesses, turn_limit(route))
+    else {
+        write!(stream, "HTTP/1.1 400 Bad Request\r\n")?;
+        return Ok(());
+    };
+
+    let mut game =
+        Game::new(word_list.clone(), &[word_list.daily(puzzle)], turn_limit).with_hard_mode(hard);
+    let mut scores = Vec::with_capacity(guesses.len());
+    for guess in &guesses {
+        match game.guess(guess) {
+            Ok(outcome) => scores.push(format!("\"{}\"", outcome.scores[0].unwrap_or_default())),
+            Err(e) => {
+                let json = format!(
+                    "{{ \"error\": \"{} is not allowed: {e}\" }}",
+                    words::to_str(guess)
+                );
+                write!(
+                    stream,
+                    "HTTP/1.1 400 Bad Request\r\nAccess-Control-Allow-Origin: *\r\nContent-Type: application/json\r\nContent-Length: {length}\r\n\r\n{json}",
+                    length = json.len()
+                )?;
+                return Ok(());
+            }
+        }
+    }
+
+    let status = match game.status() {
+        Status::Playing => "playing",
+        Status::Won => "won",
+        Status::Lost => "lost",
+    };
+    let word = if game.is_over() {
+        format!(", \"value\": \"{}\"", game.words_as_str())
+    } else {
+        String::new()
+    };
+    let json = format!(
+        "{{ \"puzzle\": {puzzle}, \"scores\": [{}], \"status\": \"{status}\", \"turns\": {turn_limit}{word} }}",
+        scores.join(", ")
+    );
+
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\nContent-Type: application/json\r\nContent-Length: {length}\r\n\r\n{json}",
+        length = json.len()
+    )?;
+
+    Ok(())
+}
+
+/// Serves the spoiler-free result grid of a game, see [`ShareGrid`].
+///
+/// `scores` holds the comma-separated scores of the guesses, e.g. `?scores=bybbg,ggggg`.
+/// Optionally `puzzle=N` names the daily puzzle, `hard=true` marks hard mode, `hints=N` notes the
+/// number of hints used, `theme=` is one of `light`, `dark` or `high-contrast` and `turns=N` sets
+/// the turn limit.
+fn handle_share(stream: &mut TcpStream, route: &str) -> Result<()> {
+    let mut scores = None;
+    let mut title = "Wordle".to_string();
+    let mut hard = false;
+    let mut hints = 0;
+    let mut theme = Theme::default();
+    let mut valid = true;
+    for (key, value) in query(route) {
+        match key {
+            "scores" => {
+                scores = value
+                    .split(',')
+                    .map(str::parse::<Score>)
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .ok();
+            }
+            "puzzle" => match value.parse::<u32>() {
+                Ok(puzzle) => title = format!("Wordle {puzzle}"),
+                Err(_) => valid = false,
+            },
+            "hard" => hard = value == "true",
+            "hints" => match value.parse() {
+                Ok(n) => hints = n,
+                Err(_) => valid = false,
+            },
+            "theme" => match value.parse() {
+                Ok(t) => theme = t,
+                Err(_) => valid = false,
+            },
+            "turns" => {}
+            _ => valid = false,
+        }
+    }
+    let (true, Some(scores), Some(turn_limit)) = (valid, scores, turn_limit(route)) else {
+        write!(stream, "HTTP/1.1 400 Bad Request\r\n")?;
+        return Ok(());
+    };
+
+    let share = ShareGrid {
+        title: &title,
+        scores: &scores,
+        turn_limit,
+        hard,
+        hints,
+        theme,
+    };
+    let json = format!("{{ \"value\": \"{}\" }}", share.to_string().replace('\n', "\\n"));
+
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\nContent-Type: application/json\r\nContent-Length: {length}\r\n\r\n{json}",
+        length = json.len()
+    )?;
+
+    Ok(())
+}
+
 fn handle_cors(stream: &mut TcpStream) -> Result<()> {
     write!(
         stream,
@@ -92,11 +292,17 @@ fn handle_not_found(stream: &mut TcpStream) -> Result<()> {
     Ok(())
 }
 
-fn handle_word(stream: &mut TcpStream) -> Result<()> {
+/// Serves a random word. `?turns=N` sets the turn limit, see [`turn_limit`].
+fn handle_word(stream: &mut TcpStream, route: &str, word_list: &WordList) -> Result<()> {
+    let Some(turns) = turn_limit(route) else {
+        write!(stream, "HTTP/1.1 400 Bad Request\r\n")?;
+        return Ok(());
+    };
+
     let mut random_state = words::Rand::default();
-    let word = words::pick_random_word(&mut random_state);
+    let word = word_list.pick_random(&mut random_state);
     let word = words::to_str(&word);
-    let json = format!("{{ \"value\": \"{word}\" }}");
+    let json = format!("{{ \"value\": \"{word}\", \"turns\": {turns} }}");
 
     write!(
         stream,
@@ -107,6 +313,28 @@ fn handle_word(stream: &mut TcpStream) -> Result<()> {
     Ok(())
 }
 
+/// Returns the key-value pairs of the query string of `route`.
+fn query(route: &str) -> impl Iterator<Item = (&str, &str)> {
+    route
+        .split_once('?')
+        .map_or("", |(_, query)| query)
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+}
+
+/// Returns the number of guesses clients should allow, given by the `turns` query parameter.
+/// Defaults to [`words::DEFAULT_TURN_LIMIT`], returns `None` if the parameter is out of range.
+fn turn_limit(route: &str) -> Option<usize> {
+    match query(route).find(|(key, _)| *key == "turns") {
+        None => Some(words::DEFAULT_TURN_LIMIT),
+        Some((_, turns)) => turns
+            .parse()
+            .ok()
+            .filter(|turns| (1..=words::MAX_TURN_LIMIT).contains(turns)),
+    }
+}
+
 fn parse_request_line(req: &str) -> Result<Request> {
     let mut req = req.split_ascii_whitespace();
     let method = req.next().unwrap();
@@ -0,0 +1,454 @@
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+use words::daily::{self, Date};
+use words::share::Theme;
+
+use crate::code::GameCode;
+use crate::palette::Palette;
+
+/// The most words that can be guessed at once, as in Octordle.
+pub const MAX_BOARDS: usize = 8;
+
+pub const USAGE: &str = "\
+usage: wordle [options]
+       wordle stats
+       wordle replay <id>
+       wordle assist [options]
+
+commands:
+    stats             show the results of past games
+    replay <id>       replay game number <id>, as printed when it ended, turn by
+                      turn
+    assist            help solve a game played elsewhere: enter each guess and
+                      the score it got, e.g. CRANE gybbg, to see the words left
+
+options:
+    --words <path>    read the answers from <path>, one word per line
+    --length <n>      play with words of <n> letters (default: 5)
+    --daily           play today's puzzle, or continue it if it was started
+    --date <date>     replay the puzzle of <date>, formatted as YYYY-MM-DD
+    --puzzle <n>      replay puzzle number <n>
+    --seed <n>        pick the word using the random seed <n>
+    --code <code>     replay the game with the shareable code <code>
+    --hard            revealed hints must be used in later guesses
+    --adversarial     play against an opponent that avoids committing to a word
+                      for as long as it can
+    --boards <n>      guess <n> words at once, e.g. 4 for Quordle (default: 1)
+    --turns <n>       allow <n> guesses (default: 5 more than the number of
+                      boards, or 20 in adversarial games)
+    --tui             play full-screen, with an on-screen keyboard
+    --theme <theme>   share results with light, dark or high-contrast squares
+                      (default: dark)
+    --palette <name>  draw letters with the classic, high-contrast or mono palette
+                      (default: classic, or mono if NO_COLOR is set or the output
+                      is not a terminal)
+    -h, --help        print this message";
+
+/// What the `wordle` binary should do.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Command {
+    /// Play a game.
+    #[default]
+    Play,
+    /// Print the statistics of past games.
+    Stats,
+    /// Replay the game with this number from the history, see [`crate::history`].
+    Replay(usize),
+    /// Narrow down the answer of a game played elsewhere, see [`crate::assist`].
+    Assist,
+}
+
+/// The command line arguments of the `wordle` binary.
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    /// A file to read the answers from instead of using the built-in list.
+    pub words: Option<PathBuf>,
+    /// The number of letters per word. Between 2 and [`words::MAX_LEN`].
+    pub length: usize,
+    /// The number of the daily puzzle to play. Picks a random word if `None`.
+    pub puzzle: Option<u32>,
+    /// The seed used to pick a random word. Seeded from the current time if `None`.
+    pub seed: Option<u64>,
+    /// Whether to enforce hard mode, see [`words::hard`].
+    pub hard: bool,
+    /// Whether to play against an adversary instead of a fixed word, see [`words::adversary`].
+    pub adversarial: bool,
+    /// The number of words guessed at once. Between 1 and [`MAX_BOARDS`].
+    pub boards: usize,
+    /// The number of guesses allowed. Between 1 and [`words::MAX_TURN_LIMIT`]. Depends on the
+    /// game if `None`, see [`Args::turn_limit`].
+    pub turns: Option<usize>,
+    /// Whether to play in the full-screen terminal UI instead of line by line.
+    pub tui: bool,
+    /// The squares the result is shared with.
+    pub theme: Theme,
+    /// How to draw letters. Detected from the environment if `None`.
+    pub palette: Option<Palette>,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            command: Command::Play,
+            words: None,
+            length: 5,
+            puzzle: None,
+            seed: None,
+            hard: false,
+            adversarial: false,
+            boards: 1,
+            turns: None,
+            tui: false,
+            theme: Theme::default(),
+            palette: None,
+        }
+    }
+}
+
+impl Args {
+    /// Parses `args`, not including the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match &*arg {
+                "--words" => parsed.words = Some(value(&mut args, "--words")?.into()),
+                "--length" => {
+                    let length = value(&mut args, "--length")?;
+                    parsed.length = match length.parse() {
+                        Ok(n) if (2..=words::MAX_LEN).contains(&n) => n,
+                        _ => return Err(ArgsError::InvalidValue("--length", length)),
+                    };
+                }
+                "--daily" => parsed.puzzle = daily::puzzle_number(Date::today()),
+                "--date" => {
+                    let date = value(&mut args, "--date")?;
+                    parsed.puzzle = date
+                        .parse()
+                        .ok()
+                        .and_then(daily::puzzle_number)
+                        .map(Some)
+                        .ok_or(ArgsError::InvalidValue("--date", date))?;
+                }
+                "--puzzle" => {
+                    let puzzle = value(&mut args, "--puzzle")?;
+                    parsed.puzzle = puzzle
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| ArgsError::InvalidValue("--puzzle", puzzle))?;
+                }
+                "--seed" => {
+                    let seed = value(&mut args, "--seed")?;
+                    parsed.seed = seed
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| ArgsError::InvalidValue("--seed", seed))?;
+                }
+                "--code" => {
+                    let code = value(&mut args, "--code")?;
+                    let Ok(GameCode {
+                        seed,
+                        length,
+                        boards,
+                    }) = code.parse()
+                    else {
+                        return Err(ArgsError::InvalidValue("--code", code));
+                    };
+                    parsed.seed = Some(seed);
+                    parsed.length = length;
+                    parsed.boards = boards;
+                }
+                "--hard" => parsed.hard = true,
+                "--adversarial" => parsed.adversarial = true,
+                "--tui" => parsed.tui = true,
+                "--theme" => {
+                    let theme = value(&mut args, "--theme")?;
+                    parsed.theme = theme
+                        .parse()
+                        .map_err(|_| ArgsError::InvalidValue("--theme", theme))?;
+                }
+                "--turns" => {
+                    let turns = value(&mut args, "--turns")?;
+                    parsed.turns = match turns.parse() {
+                        Ok(n) if (1..=words::MAX_TURN_LIMIT).contains(&n) => Some(n),
+                        _ => return Err(ArgsError::InvalidValue("--turns", turns)),
+                    };
+                }
+                "--boards" => {
+                    let boards = value(&mut args, "--boards")?;
+                    parsed.boards = match boards.parse() {
+                        Ok(n) if (1..=MAX_BOARDS).contains(&n) => n,
+                        _ => return Err(ArgsError::InvalidValue("--boards", boards)),
+                    };
+                }
+                "--palette" => {
+                    let palette = value(&mut args, "--palette")?;
+                    parsed.palette = palette
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| ArgsError::InvalidValue("--palette", palette))?;
+                }
+                "stats" => parsed.command = Command::Stats,
+                "replay" => {
+                    let id = value(&mut args, "replay")?;
+                    parsed.command = match id.parse() {
+                        Ok(id) if id > 0 => Command::Replay(id),
+                        _ => return Err(ArgsError::InvalidValue("replay", id)),
+                    };
+                }
+                "assist" => parsed.command = Command::Assist,
+                "-h" | "--help" => return Err(ArgsError::Help),
+                _ => return Err(ArgsError::Unknown(arg)),
+            }
+        }
+
+        if parsed.adversarial && (parsed.puzzle.is_some() || parsed.seed.is_some()) {
+            return Err(ArgsError::Conflict("--adversarial", "a puzzle or seed"));
+        }
+        if parsed.seed.is_some() && parsed.puzzle.is_some() {
+            return Err(ArgsError::Conflict("--seed or --code", "a puzzle"));
+        }
+        if parsed.boards > 1 && parsed.adversarial {
+            return Err(ArgsError::Conflict("--boards", "--adversarial"));
+        }
+        if parsed.boards > 1 && parsed.puzzle.is_some() {
+            return Err(ArgsError::Conflict("--boards", "a puzzle"));
+        }
+
+        Ok(parsed)
+    }
+
+    /// Returns the number of guesses allowed: `--turns` if given, otherwise
+    /// [`words::MAX_TURN_LIMIT`] in adversarial games, which have no real limit, and 5 more than
+    /// the number of boards in all others.
+    pub fn turn_limit(&self) -> usize {
+        match self.turns {
+            Some(turns) => turns,
+            None if self.adversarial => words::MAX_TURN_LIMIT,
+            None => words::DEFAULT_TURN_LIMIT + self.boards - 1,
+        }
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &'static str) -> Result<String, ArgsError> {
+    args.next().ok_or(ArgsError::MissingValue(flag))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ArgsError {
+    /// The user asked for the usage message.
+    Help,
+    Unknown(String),
+    MissingValue(&'static str),
+    InvalidValue(&'static str, String),
+    /// The flag can't be combined with the other options.
+    Conflict(&'static str, &'static str),
+}
+
+impl Error for ArgsError {}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Help => write!(f, "{USAGE}"),
+            Self::Unknown(arg) => write!(f, "unknown argument: {arg}"),
+            Self::MissingValue(flag) => write!(f, "{flag} expects a value"),
+            Self::InvalidValue(flag, value) => write!(f, "invalid value for {flag}: {value}"),
+            Self::Conflict(flag, other) => write!(f, "{flag} can't be used with {other}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgsError> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_turn_limit() {
+        let turn_limit = |args: &[&str]| parse(args).unwrap().turn_limit();
+        assert_eq!(turn_limit(&[]), 6);
+        assert_eq!(turn_limit(&["--boards", "4"]), 9);
+        assert_eq!(turn_limit(&["--boards", "4", "--turns", "7"]), 7);
+        assert_eq!(turn_limit(&["--adversarial"]), words::MAX_TURN_LIMIT);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(&[]), Ok(Args::default()));
+        assert_eq!(
+            parse(&["--words", "data/words.txt"]),
+            Ok(Args {
+                words: Some("data/words.txt".into()),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--length", "7"]),
+            Ok(Args {
+                length: 7,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--date", "2022-01-01"]),
+            Ok(Args {
+                puzzle: Some(196),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--puzzle", "196"]),
+            parse(&["--date", "2022-01-01"])
+        );
+        assert!(parse(&["--daily"]).unwrap().puzzle.is_some());
+        assert_eq!(
+            parse(&["--date", "2021-01-01"]),
+            Err(ArgsError::InvalidValue("--date", "2021-01-01".into()))
+        );
+        assert_eq!(
+            parse(&["--seed", "42"]),
+            Ok(Args {
+                seed: Some(42),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--code", "3000000-000001A"]),
+            Ok(Args {
+                seed: Some(42),
+                length: 6,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--code", "AG00000-000001A"]),
+            Ok(Args {
+                seed: Some(42),
+                boards: 2,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--seed", "-1"]),
+            Err(ArgsError::InvalidValue("--seed", "-1".into()))
+        );
+        assert_eq!(
+            parse(&["--length", "11"]),
+            Err(ArgsError::InvalidValue("--length", "11".into()))
+        );
+        assert_eq!(
+            parse(&["--hard", "--length", "6"]),
+            Ok(Args {
+                hard: true,
+                length: 6,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--turns", "8"]),
+            Ok(Args {
+                turns: Some(8),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--turns", "0"]),
+            Err(ArgsError::InvalidValue("--turns", "0".into()))
+        );
+        assert_eq!(
+            parse(&["--theme", "light"]),
+            Ok(Args {
+                theme: Theme::Light,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--palette", "mono"]),
+            Ok(Args {
+                palette: Some(Palette::Mono),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["stats"]),
+            Ok(Args {
+                command: Command::Stats,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["replay", "12", "--palette", "mono"]),
+            Ok(Args {
+                command: Command::Replay(12),
+                palette: Some(Palette::Mono),
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["replay", "0"]),
+            Err(ArgsError::InvalidValue("replay", "0".into()))
+        );
+        assert_eq!(parse(&["replay"]), Err(ArgsError::MissingValue("replay")));
+        assert_eq!(
+            parse(&["assist", "--length", "6"]),
+            Ok(Args {
+                command: Command::Assist,
+                length: 6,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--adversarial", "--hard"]),
+            Ok(Args {
+                adversarial: true,
+                hard: true,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--adversarial", "--seed", "42"]),
+            Err(ArgsError::Conflict("--adversarial", "a puzzle or seed"))
+        );
+        assert_eq!(
+            parse(&["--seed", "42", "--daily"]),
+            Err(ArgsError::Conflict("--seed or --code", "a puzzle"))
+        );
+        assert_eq!(
+            parse(&["--puzzle", "3", "--code", "2G00000-000001A"]),
+            Err(ArgsError::Conflict("--seed or --code", "a puzzle"))
+        );
+        assert_eq!(
+            parse(&["--adversarial", "--boards", "2"]),
+            Err(ArgsError::Conflict("--boards", "--adversarial"))
+        );
+        assert_eq!(
+            parse(&["--boards", "4"]),
+            Ok(Args {
+                boards: 4,
+                ..Args::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--boards", "9"]),
+            Err(ArgsError::InvalidValue("--boards", "9".into()))
+        );
+        assert_eq!(
+            parse(&["--boards", "2", "--daily"]),
+            Err(ArgsError::Conflict("--boards", "a puzzle"))
+        );
+        assert_eq!(parse(&["--words"]), Err(ArgsError::MissingValue("--words")));
+        assert_eq!(parse(&["--help"]), Err(ArgsError::Help));
+        assert_eq!(
+            parse(&["--wrods"]),
+            Err(ArgsError::Unknown("--wrods".into()))
+        );
+    }
+}
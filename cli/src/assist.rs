@@ -0,0 +1,211 @@
+//! Helping to solve games played elsewhere.
+//!
+//! The player enters each guess they made together with the score they got, and is shown the
+//! words that are still possible and the guesses that narrow them down the most.
+use std::error::Error;
+use std::fmt;
+use std::io::{self, stdin, stdout, BufRead, Write};
+
+use words::score::{LetterScore, ParseScoreError, Score};
+use words::solver::Solver;
+use words::{Word, WordList};
+
+use crate::error::InvalidInputError;
+use crate::palette::Palette;
+use crate::{hint, input};
+
+/// The most remaining words that are listed, more are only counted.
+const MAX_LISTED: usize = 30;
+
+/// The number of suggested guesses.
+const SUGGESTIONS: usize = 5;
+
+/// Reads guesses and their scores from stdin until the word is found or the input ends, printing
+/// the remaining words of `word_list` after each of them.
+pub fn run<const N: usize>(word_list: &WordList<N>, palette: Palette) -> io::Result<()> {
+    let mut solver = hint::solver(word_list, hint::matrix_path().as_deref());
+    let mut stdin = stdin().lock();
+    let mut line = Vec::new();
+
+    print_candidates(&solver);
+    while solver.candidates().len() > 1 {
+        print!("\nYour guess and its score: ");
+        stdout().flush()?;
+        line.clear();
+        if stdin.read_until(b'\n', &mut line)? == 0 {
+            println!();
+            return Ok(());
+        }
+        let (guess, score) = match parse_feedback::<N>(&String::from_utf8_lossy(&line)) {
+            Ok(feedback) => feedback,
+            Err(e) => {
+                eprintln!("{e}");
+                continue;
+            }
+        };
+
+        let mut rendered = String::new();
+        crate::render(&mut rendered, &guess, score, palette).expect("OOM");
+        println!("{rendered}\n");
+        if score.is_win() {
+            println!("🎉🎊🥳");
+            return Ok(());
+        }
+        solver.record(&guess, score);
+        print_candidates(&solver);
+    }
+    Ok(())
+}
+
+fn print_candidates<const N: usize>(solver: &Solver<N>) {
+    let candidates = solver.candidates();
+    match candidates {
+        [] => println!("No words are left, check the scores you entered."),
+        [word] => println!("The word is {}.", words::to_str(word)),
+        _ => {
+            println!("{} words are left.", candidates.len());
+            if candidates.len() <= MAX_LISTED {
+                let listed: Vec<_> = candidates.iter().map(words::to_str).collect();
+                println!("{}", listed.join(" "));
+            }
+            let suggestions: Vec<_> = solver
+                .best_guesses(SUGGESTIONS)
+                .into_iter()
+                .map(|(guess, bits)| format!("{} ({bits:.2} bits)", words::to_str(&guess)))
+                .collect();
+            println!("Try: {}", suggestions.join(", "));
+        }
+    }
+}
+
+/// Parses a guess and the score it got, either as the guess followed by its score as parsed by
+/// [`Score::from_str`](std::str::FromStr), e.g. `CRANE gybbg`, or as drawn by
+/// [`render`](crate::render) with [`Palette::Mono`], e.g. `[C](R) a  n  e `.
+fn parse_feedback<const N: usize>(line: &str) -> Result<(Word<N>, Score<N>), FeedbackError> {
+    // a guess has at least 2 letters, so single letters are drawn ones, e.g. when all are wrong
+    let parts: Vec<_> = line.split_whitespace().collect();
+    if line.contains(['[', '('])
+        || parts.len() > 1 && parts.iter().all(|part| part.chars().count() == 1)
+    {
+        return parse_rendered(line);
+    }
+
+    let [guess, score] = parts[..] else {
+        return Err(FeedbackError::Format);
+    };
+    let guess = input::parse_guess(guess).map_err(FeedbackError::Guess)?;
+    let score = score.parse().map_err(FeedbackError::Score)?;
+    Ok((guess, score))
+}
+
+/// Parses a guess drawn with [`Palette::Mono`]: `[A]` for right letters, `(A)` for letters in the
+/// word and lowercase letters for wrong ones. Spaces are ignored.
+fn parse_rendered<const N: usize>(line: &str) -> Result<(Word<N>, Score<N>), FeedbackError> {
+    let mut letters = Vec::with_capacity(N);
+    let mut chars = line.trim().chars().filter(|c| *c != ' ');
+    while let Some(c) = chars.next() {
+        let (letter, close) = match c {
+            '[' => (LetterScore::Right, Some(']')),
+            '(' => (LetterScore::InWord, Some(')')),
+            _ => (LetterScore::Wrong, None),
+        };
+        let c = if close.is_some() {
+            chars.next().ok_or(FeedbackError::Format)?
+        } else {
+            c
+        };
+        if !c.is_ascii_alphabetic() {
+            return Err(FeedbackError::Guess(InvalidInputError::NotALetter(c)));
+        }
+        if close.is_some() && chars.next() != close {
+            return Err(FeedbackError::Format);
+        }
+        letters.push((c.to_ascii_uppercase() as u8, letter));
+    }
+
+    if letters.len() != N {
+        return Err(FeedbackError::Guess(InvalidInputError::InputLength(N)));
+    }
+    let mut guess = [0; N];
+    let mut score = Score::default();
+    for (i, (c, letter)) in letters.into_iter().enumerate() {
+        guess[i] = c;
+        score.set(i, letter);
+    }
+    Ok((guess, score))
+}
+
+/// The error returned when a line entered in assist mode can't be parsed.
+#[derive(Debug)]
+enum FeedbackError {
+    /// The line isn't a guess followed by a score.
+    Format,
+    Guess(InvalidInputError),
+    Score(ParseScoreError),
+}
+
+impl Error for FeedbackError {}
+
+impl fmt::Display for FeedbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Format => write!(f, "expected a guess and its score, e.g. CRANE gybbg"),
+            Self::Guess(e) => write!(f, "{e}"),
+            Self::Score(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use words::score::score;
+
+    fn parse(line: &str) -> Result<(Word, Score), String> {
+        parse_feedback(line).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_parse_feedback() {
+        let expected = (*b"CRANE", "gybbg".parse().unwrap());
+        assert_eq!(parse("CRANE gybbg\n"), Ok(expected));
+        assert_eq!(parse("  crane  GYBBG "), Ok(expected));
+        assert_eq!(parse("[C](R) a  n [E]\n"), Ok(expected));
+        assert_eq!(parse("[c](r)an[e]"), Ok(expected));
+
+        // the inverse of render
+        let guess = *b"FOCAL";
+        let score = score(b"CLOAK", &guess);
+        let mut rendered = String::new();
+        crate::render(&mut rendered, &guess, score, Palette::Mono).unwrap();
+        assert_eq!(parse(&rendered), Ok((guess, score)));
+        // with every letter wrong, there are no brackets
+        let wrong = Score::default();
+        let mut rendered = String::new();
+        crate::render(&mut rendered, b"CRANE", wrong, Palette::Mono).unwrap();
+        assert_eq!(rendered, " c  r  a  n  e ");
+        assert_eq!(parse(&rendered), Ok((*b"CRANE", wrong)));
+
+        let format = "expected a guess and its score, e.g. CRANE gybbg";
+        assert_eq!(parse("CRANE"), Err(format.into()));
+        assert_eq!(parse("\n"), Err(format.into()));
+        assert_eq!(parse("CRANE gybbg x"), Err(format.into()));
+        assert_eq!(parse("[C(R) a  n [E]"), Err(format.into()));
+        assert_eq!(
+            parse("CRAN gybbg"),
+            Err("Guess must have 5 characters.".into())
+        );
+        assert_eq!(
+            parse("[C](R) a  n "),
+            Err("Guess must have 5 characters.".into())
+        );
+        assert_eq!(
+            parse("CRANE gybb"),
+            Err("score must have 5 characters, found 4".into())
+        );
+        assert_eq!(
+            parse("[C](4) a  n [E]"),
+            Err("expected a letter, found: '4'".into())
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Shareable game codes.
+//!
+//! A game code encodes everything needed to replay a random game: the seed, the word length and
+//! the number of boards. Playing a code with the same word list always picks the same words.
+//!
+//! Codes are written in Crockford's base32, so they are case-insensitive and `I`, `L` and `O`
+//! are read as `1`, `1` and `0`.
+use std::fmt;
+use std::str::FromStr;
+
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+use crate::args::MAX_BOARDS;
+
+/// The number of base32 digits in a code, enough for a `u64` seed, a 4-bit length and the number
+/// of boards less one in 3 bits. A leading 0 is left out, so codes of a single board have the 14
+/// digits they had before there could be more.
+const DIGITS: usize = 15;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameCode {
+    pub seed: u64,
+    pub length: usize,
+    pub boards: usize,
+}
+
+impl fmt::Display for GameCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value =
+            ((self.boards - 1) as u128) << 68 | (self.length as u128) << 64 | self.seed as u128;
+        let digits = if value >> ((DIGITS - 1) * 5) == 0 {
+            DIGITS - 1
+        } else {
+            DIGITS
+        };
+        for i in (0..digits).rev() {
+            let digit = (value >> (i * 5)) as usize & 0x1f;
+            write!(f, "{}", ALPHABET[digit] as char)?;
+            if i == DIGITS / 2 {
+                write!(f, "-")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GameCode {
+    type Err = InvalidCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value = 0u128;
+        let mut digits = 0;
+        for c in s.chars().filter(|c| *c != '-') {
+            let c = match c.to_ascii_uppercase() {
+                'I' | 'L' => '1',
+                'O' => '0',
+                c => c,
+            };
+            let digit = ALPHABET
+                .iter()
+                .position(|d| *d as char == c)
+                .ok_or(InvalidCode)?;
+            value = value << 5 | digit as u128;
+            digits += 1;
+        }
+
+        let length = (value >> 64) as usize & 0xf;
+        let boards = (value >> 68) as usize + 1;
+        if !(DIGITS - 1..=DIGITS).contains(&digits)
+            || !(2..=words::MAX_LEN).contains(&length)
+            || !(1..=MAX_BOARDS).contains(&boards)
+        {
+            return Err(InvalidCode);
+        }
+        Ok(Self {
+            seed: value as u64,
+            length,
+            boards,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidCode;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        for (seed, length, boards, digits) in [
+            (0, 5, 1, 14),
+            (1, 2, 1, 14),
+            (u64::MAX, 10, 1, 14),
+            (0x1234_5678_9abc_def0, 7, 1, 14),
+            (42, 5, 4, 14),
+            (42, 5, 5, 15),
+            (u64::MAX, 10, 8, 15),
+        ] {
+            let code = GameCode {
+                seed,
+                length,
+                boards,
+            };
+            let s = code.to_string();
+            assert_eq!(s.len(), digits + 1, "{s}");
+            assert_eq!(s.parse(), Ok(code), "{s}");
+            assert_eq!(s.to_lowercase().parse(), Ok(code), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let valid = GameCode {
+            seed: 42,
+            length: 5,
+            boards: 1,
+        }
+        .to_string();
+        assert_eq!(valid, "2G00000-000001A");
+        assert_eq!(
+            "2gOOOOO-OOOOOla".parse(),
+            Ok(GameCode {
+                seed: 42,
+                length: 5,
+                boards: 1,
+            })
+        );
+        for invalid in [
+            "",
+            "2G00000-00001A",
+            "2G00000-00000U1",
+            // lengths 11 and 1
+            "5G00000-000001A",
+            "0G00000-000001A",
+            // 9 boards
+            "22G00000-000001A",
+            "2G00000-0000001A",
+            "0002G00000-000001A",
+        ] {
+            assert_eq!(invalid.parse::<GameCode>(), Err(InvalidCode), "{invalid}");
+        }
+    }
+}
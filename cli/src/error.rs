@@ -1,10 +1,18 @@
 use std::error::Error;
 use std::fmt;
 
+use words::hard::Violation;
+
 #[derive(Debug)]
 pub enum InvalidInputError {
-    InputLength,
-    NonAscii(u8),
+    /// The guess did not have the given number of letters.
+    InputLength(usize),
+    /// The guess contained something other than an ASCII letter.
+    NotALetter(char),
+    /// The guess is not in the dictionary. Holds the closest word, if there is one.
+    NotAWord(String, Option<String>),
+    /// The guess ignored a hint while playing in hard mode.
+    HardMode(Violation),
 }
 
 impl Error for InvalidInputError {}
@@ -12,11 +20,20 @@ impl Error for InvalidInputError {}
 impl fmt::Display for InvalidInputError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::InputLength => {
-                write!(f, "Guess must have 5 characters.")?;
+            Self::InputLength(len) => {
+                write!(f, "Guess must have {len} characters.")?;
+            }
+            Self::NotALetter(invalid_char) => {
+                write!(f, "expected a letter, found: {:?}", invalid_char)?;
+            }
+            Self::NotAWord(guess, suggestion) => {
+                write!(f, "{guess} is not a word.")?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " Did you mean {suggestion}?")?;
+                }
             }
-            Self::NonAscii(invalid_char) => {
-                write!(f, "expected ascii, found: {:#x}", invalid_char)?;
+            Self::HardMode(violation) => {
+                write!(f, "Hard mode: {violation}.")?;
             }
         }
 
@@ -0,0 +1,159 @@
+//! Hints that get more revealing the more of them the player asks for.
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use engine::{Board, Game};
+use words::matrix::ScoreMatrix;
+use words::score::LetterScore;
+use words::solver::Solver;
+use words::{Word, WordList};
+
+use crate::stats;
+
+/// A hint about the word of a [`Board`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint<const N: usize> {
+    /// The number of words that are still possible.
+    Remaining(usize),
+    /// A letter that is in the word, which wasn't revealed yet.
+    Letter(u8),
+    /// A guess that narrows down the remaining words the most.
+    Guess(Word<N>),
+}
+
+/// Returns the next hint for `board` after `given` hints were given: first how many words are
+/// still possible, then a letter of the word and then suggested guesses. `solver` has recorded the
+/// guesses on `board`, see [`Solvers::get`].
+pub fn next<const N: usize>(board: &Board<N>, given: usize, solver: &Solver<N>) -> Hint<N> {
+    if given == 0 {
+        return Hint::Remaining(solver.candidates().len());
+    }
+    if given == 1 {
+        let unrevealed = board.word().iter().find(|&&c| {
+            let revealed = matches!(
+                board.letters()[(c - b'A') as usize],
+                Some(LetterScore::InWord | LetterScore::Right)
+            );
+            // an adversary may still switch to any of the candidates
+            let certain =
+                !board.is_adversarial() || solver.candidates().iter().all(|word| word.contains(&c));
+            !revealed && certain
+        });
+        if let Some(&c) = unrevealed {
+            return Hint::Letter(c);
+        }
+    }
+    match solver.best_guesses(1).first() {
+        Some(&(guess, _)) => Hint::Guess(guess),
+        // the word is always a candidate, this is just to be safe
+        None => Hint::Guess(*board.word()),
+    }
+}
+
+/// The solvers hints of a game are drawn from, one per board. They are built on the first hint, as
+/// that takes a while, and afterwards only record the guesses made since the last one.
+pub struct Solvers<const N: usize> {
+    /// Where the score matrix is cached, see [`solver`].
+    cache: Option<PathBuf>,
+    /// The solver of each board and the number of its guesses it has recorded.
+    boards: Vec<(Solver<N>, usize)>,
+}
+
+impl<const N: usize> Solvers<N> {
+    /// Creates the solvers of a game, whose score matrix is cached at `cache`.
+    pub fn new(cache: Option<PathBuf>) -> Self {
+        Self {
+            cache,
+            boards: Vec::new(),
+        }
+    }
+
+    /// Returns the solver of the board at `index` of `game`, which has recorded its guesses.
+    pub fn get(&mut self, game: &Game<N>, index: usize) -> &Solver<N> {
+        if self.boards.is_empty() {
+            let solver = solver(game.word_list(), self.cache.as_deref());
+            self.boards = vec![(solver, 0); game.boards().len()];
+        }
+        let (solver, recorded) = &mut self.boards[index];
+        let guesses = game.boards()[index].guesses();
+        for (guess, score) in &guesses[*recorded..] {
+            solver.record(guess, *score);
+        }
+        *recorded = guesses.len();
+        solver
+    }
+}
+
+/// Returns the file the scores of [`solver`] are cached in: `scores` in [`stats::data_dir`].
+pub fn matrix_path() -> Option<PathBuf> {
+    Some(stats::data_dir()?.join("scores"))
+}
+
+/// Returns a solver whose answer is one of `word_list`, and which suggests any allowed guess: the
+/// words of `word_list` and, for 5-letter words, the built-in ones.
+///
+/// Ranking every guess takes millions of scores, so they are looked up in a [`ScoreMatrix`]. It
+/// is cached at `cache` if given, and is built again whenever the word list changes.
+pub fn solver<const N: usize>(word_list: &WordList<N>, cache: Option<&Path>) -> Solver<N> {
+    let candidates = word_list.words().to_vec();
+    let builtin = words::WORDS
+        .iter()
+        .chain(&words::GUESSES)
+        .filter_map(|word| Word::<N>::try_from(&word[..]).ok())
+        .filter(|word| !word_list.contains(word));
+    let guesses: Vec<_> = candidates.iter().copied().chain(builtin).collect();
+    let matrix = match cache {
+        Some(path) => ScoreMatrix::cached(path, &guesses, &candidates),
+        None => ScoreMatrix::build(&guesses, &candidates),
+    };
+    let solver = Solver::with_words(candidates, guesses);
+    match matrix {
+        Some(matrix) => solver.with_matrix(Arc::new(matrix)),
+        None => solver,
+    }
+}
+
+impl<const N: usize> fmt::Display for Hint<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Remaining(1) => write!(f, "Hint: only 1 word is left."),
+            Self::Remaining(n) => write!(f, "Hint: {n} words are left."),
+            Self::Letter(c) => write!(f, "Hint: the word contains {}.", *c as char),
+            Self::Guess(guess) => write!(f, "Hint: try {}.", words::to_str(guess)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() {
+        let word_list = WordList::from_reader(&b"CIGAR\nREBUT\nSISSY\nHUMPH\nCLOSE"[..]).unwrap();
+        let mut game = Game::new(word_list, &[*b"CLOSE"], 6);
+        // built on the first hint, then CIGAR is recorded on the second
+        let mut solvers = Solvers::new(None);
+        let mut hints = Vec::new();
+        for (given, guess) in [None, Some(b"CIGAR"), None, None].into_iter().enumerate() {
+            if let Some(guess) = guess {
+                game.guess(guess).unwrap();
+            }
+            hints.push(next(&game.boards()[0], given, solvers.get(&game, 0)));
+        }
+        // C is known to be right after CIGAR, and only CLOSE is left
+        assert_eq!(
+            hints,
+            [
+                Hint::Remaining(5),
+                Hint::Letter(b'L'),
+                Hint::Guess(*b"CLOSE"),
+                Hint::Guess(*b"CLOSE")
+            ]
+        );
+        assert_eq!(hints[0].to_string(), "Hint: 5 words are left.");
+        assert_eq!(hints[1].to_string(), "Hint: the word contains L.");
+        assert_eq!(hints[2].to_string(), "Hint: try CLOSE.");
+    }
+}
@@ -0,0 +1,314 @@
+//! The log of finished games.
+//!
+//! Every finished game is appended to the history file as one line, and lines are never changed
+//! afterwards. Games are numbered by their line, counting from 1, which is how
+//! `wordle replay <id>` picks them. The statistics are computed from the history, see
+//! [`Stats::record`](crate::stats::Stats::record).
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use engine::Game;
+use words::{file, Date};
+
+use crate::save::Origin;
+use crate::stats;
+
+/// The version of the format of the history file.
+const VERSION: u32 = 1;
+
+/// Returns the file finished games are logged in: `history` in [`stats::data_dir`].
+pub fn default_path() -> Option<PathBuf> {
+    Some(stats::data_dir()?.join("history"))
+}
+
+/// A finished game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// The day the game was finished.
+    pub date: Date,
+    /// The time spent playing.
+    pub duration: Duration,
+    pub origin: Origin,
+    pub turn_limit: usize,
+    pub hard: bool,
+    /// The number of hints the player asked for.
+    pub hints: usize,
+    /// The words of the boards, in order.
+    pub answers: Vec<String>,
+    pub guesses: Vec<String>,
+}
+
+impl Entry {
+    /// Creates the entry of `game`, which is over.
+    pub fn new<const N: usize>(game: &Game<N>, origin: Origin, duration: Duration) -> Self {
+        Self {
+            date: Date::today(),
+            duration,
+            origin,
+            turn_limit: game.turn_limit(),
+            hard: game.is_hard(),
+            hints: game.hints(),
+            answers: game
+                .boards()
+                .iter()
+                .map(|board| board.word_as_str().to_string())
+                .collect(),
+            guesses: game
+                .history()
+                .iter()
+                .map(|outcome| words::to_str(&outcome.guess).to_string())
+                .collect(),
+        }
+    }
+
+    /// Returns the number of letters of the words.
+    pub fn length(&self) -> usize {
+        self.answers[0].len()
+    }
+
+    /// Returns whether every word was guessed.
+    pub fn is_won(&self) -> bool {
+        self.answers
+            .iter()
+            .all(|answer| self.guesses.contains(answer))
+    }
+}
+
+impl fmt::Display for Entry {
+    /// Writes the entry as a line of the history file, without the newline, e.g.
+    /// `2024-05-01 93 6 0 1 FOCAL CRANE,FOCAL seed 42`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {} {}",
+            self.date,
+            self.duration.as_secs(),
+            self.turn_limit,
+            self.hard as u8,
+            self.hints,
+            self.answers.join(","),
+            self.guesses.join(","),
+            self.origin
+        )
+    }
+}
+
+impl FromStr for Entry {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // the origin comes last, as it may contain a space
+        let fields: Vec<_> = s.splitn(8, ' ').collect();
+        let [date, duration, turn_limit, hard, hints, answers, guesses, origin] = fields[..] else {
+            return Err("expected 8 fields".into());
+        };
+        let entry = Self {
+            date: date.parse().map_err(|_| format!("invalid date: {date}"))?,
+            duration: Duration::from_secs(duration.parse()?),
+            turn_limit: turn_limit.parse()?,
+            hard: match hard {
+                "0" => false,
+                "1" => true,
+                _ => return Err(format!("invalid hard mode: {hard}").into()),
+            },
+            hints: hints.parse()?,
+            answers: answers.split(',').map(str::to_string).collect(),
+            guesses: guesses.split(',').map(str::to_string).collect(),
+            origin: origin.parse()?,
+        };
+
+        let length = entry.length();
+        let words = entry.answers.iter().chain(&entry.guesses);
+        if !(2..=words::MAX_LEN).contains(&length)
+            || !words.clone().all(|word| word.len() == length)
+            || !words
+                .flat_map(|word| word.bytes())
+                .all(|c| c.is_ascii_uppercase())
+        {
+            return Err("invalid words".into());
+        }
+        if !(1..=words::MAX_TURN_LIMIT).contains(&entry.turn_limit) {
+            return Err(format!("invalid turn limit: {turn_limit}").into());
+        }
+        if entry.guesses.len() > entry.turn_limit {
+            return Err("more guesses than turns".into());
+        }
+        // the game ends once every word is guessed
+        let solved = (1..=entry.guesses.len()).find(|&turns| {
+            let guesses = &entry.guesses[..turns];
+            entry.answers.iter().all(|answer| guesses.contains(answer))
+        });
+        if solved.is_some_and(|turns| turns < entry.guesses.len()) {
+            return Err("guesses after the game was won".into());
+        }
+        Ok(entry)
+    }
+}
+
+/// Reads the games logged at `path`, see [`file::load_or_default`].
+pub fn load(path: &Path) -> Result<Vec<Entry>, Box<dyn Error>> {
+    file::load_or_default(path, deserialize)
+}
+
+/// Reads games logged by [`append`].
+pub fn deserialize(mut r: impl Read) -> Result<Vec<Entry>, Box<dyn Error>> {
+    let mut buf = String::new();
+    r.read_to_string(&mut buf)?;
+
+    let mut lines = buf.lines();
+    match lines.next().map(|line| line.strip_prefix('v')) {
+        None => return Ok(Vec::new()),
+        Some(Some(version)) if version == VERSION.to_string() => {}
+        Some(_) => return Err("unsupported history version".into()),
+    }
+    lines
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|e| format!("game {}: {e}", i + 1).into())
+        })
+        .collect()
+}
+
+/// Appends `entry` to the history at `path`, returning its number.
+///
+/// Concurrent appends are serialized through a lock file next to `path`, so every game gets its
+/// own number.
+pub fn append(path: &Path, entry: &Entry) -> Result<usize, Box<dyn Error>> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let lock = File::create(path.with_extension("lock"))?;
+    lock.lock()?;
+
+    // a line that can't be read must not keep later games from being logged
+    let games = match fs::read_to_string(path) {
+        Ok(history) => history.lines().skip(1).count(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+        Err(e) => return Err(e.into()),
+    };
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "v{VERSION}")?;
+    }
+    writeln!(file, "{entry}")?;
+    file.sync_all()?;
+    Ok(games + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn entry(answers: &[&str], guesses: &[&str]) -> Entry {
+        Entry {
+            date: Date::new(2024, 5, 1).unwrap(),
+            duration: Duration::from_secs(93),
+            origin: Origin::Seed {
+                seed: 42,
+                boards: 1,
+            },
+            turn_limit: 6,
+            hard: false,
+            hints: 1,
+            answers: answers.iter().map(|word| word.to_string()).collect(),
+            guesses: guesses.iter().map(|word| word.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_entry() {
+        let won = entry(&["FOCAL"], &["CRANE", "FOCAL"]);
+        assert!(won.is_won());
+        assert_eq!(
+            won.to_string(),
+            "2024-05-01 93 6 0 1 FOCAL CRANE,FOCAL seed 42"
+        );
+        assert_eq!(won.to_string().parse::<Entry>().unwrap(), won);
+
+        let mut lost = entry(&["FOCAL", "ERASE"], &["CRANE", "FOCAL"]);
+        lost.origin = Origin::Seed {
+            seed: 42,
+            boards: 2,
+        };
+        assert!(!lost.is_won());
+        assert_eq!(
+            lost.to_string(),
+            "2024-05-01 93 6 0 1 FOCAL,ERASE CRANE,FOCAL seed 42 2"
+        );
+        assert_eq!(lost.to_string().parse::<Entry>().unwrap(), lost);
+        lost.origin = Origin::Adversarial;
+        assert_eq!(lost.to_string().parse::<Entry>().unwrap(), lost);
+
+        for invalid in [
+            "",
+            "2024-05-01 93 6 0 1 FOCAL CRANE,FOCAL",
+            "2024-13-01 93 6 0 1 FOCAL CRANE,FOCAL seed 42",
+            "2024-05-01 93 6 2 1 FOCAL CRANE,FOCAL seed 42",
+            "2024-05-01 93 6 0 1 FOCAL CRANE,FOCA seed 42",
+            "2024-05-01 93 6 0 1 focal CRANE,FOCAL seed 42",
+            "2024-05-01 93 1 0 1 FOCAL CRANE,FOCAL seed 42",
+            "2024-05-01 93 0 0 1 FOCAL CRANE,FOCAL seed 42",
+            "2024-05-01 93 18446744073709551615 0 0 FOCAL FOCAL seed 42",
+            "2024-05-01 93 6 0 1 FOCAL FOCAL,FOCAL seed 42",
+            "2024-05-01 93 6 0 1 FOCAL,ERASE ERASE,FOCAL,CRANE seed 42",
+            "2024-05-01 93 6 0 1 FOCAL CRANE,FOCAL random",
+        ] {
+            assert!(invalid.parse::<Entry>().is_err(), "{invalid:?}");
+        }
+    }
+
+    #[test]
+    fn test_append() {
+        let dir = env::temp_dir().join(format!("wordle-history-{}", std::process::id()));
+        let path = dir.join("history");
+        assert_eq!(load(&path).unwrap(), []);
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..10 {
+                        append(&path, &entry(&["FOCAL"], &["FOCAL"])).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        let id = append(&path, &entry(&["ERASE"], &["CRANE"])).unwrap();
+
+        let entries = load(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(id, 81);
+        assert_eq!(entries.len(), 81);
+        assert_eq!(entries[80], entry(&["ERASE"], &["CRANE"]));
+        assert!(deserialize(&b"v2\n"[..]).is_err());
+    }
+
+    #[test]
+    fn test_append_corrupt() {
+        let dir = env::temp_dir().join(format!("wordle-corrupt-{}", std::process::id()));
+        let path = dir.join("history");
+        append(&path, &entry(&["FOCAL"], &["FOCAL"])).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "not a game").unwrap();
+
+        let id = append(&path, &entry(&["ERASE"], &["CRANE"])).unwrap();
+        let history = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(id, 3);
+        assert_eq!(
+            history.lines().last(),
+            Some(&*entry(&["ERASE"], &["CRANE"]).to_string())
+        );
+    }
+}
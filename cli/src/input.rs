@@ -0,0 +1,114 @@
+//! Reading guesses line by line, from a terminal or from redirected input.
+use std::io::{self, BufRead};
+
+use words::Word;
+
+use crate::error::InvalidInputError;
+
+/// A line entered at the prompt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Entry<const N: usize> {
+    Guess(Word<N>),
+    /// `?` or `!hint`, asking for a hint, see [`crate::hint`].
+    Hint,
+}
+
+/// Reads one guess per line from a [`BufRead`].
+pub struct Input<R> {
+    reader: R,
+    line: Vec<u8>,
+}
+
+impl<R: BufRead> Input<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: Vec::new(),
+        }
+    }
+
+    /// Reads the next guess or request for a hint. Surrounding whitespace is ignored and letters
+    /// are converted to uppercase.
+    ///
+    /// Returns `Ok(None)` once the input is exhausted, and `Ok(Some(Err(_)))` if the line is not a
+    /// valid guess, in which case the next call reads the following line.
+    pub fn read_entry<const N: usize>(
+        &mut self,
+    ) -> io::Result<Option<Result<Entry<N>, InvalidInputError>>> {
+        self.line.clear();
+        if self.reader.read_until(b'\n', &mut self.line)? == 0 {
+            return Ok(None);
+        }
+
+        // invalid UTF-8 turns into replacement characters, which are rejected below
+        let line = String::from_utf8_lossy(&self.line);
+        let entry = match line.trim() {
+            "?" | "!hint" => Ok(Entry::Hint),
+            guess => parse_guess(guess).map(Entry::Guess),
+        };
+        Ok(Some(entry))
+    }
+}
+
+/// Parses a single guess, converting it to uppercase.
+pub fn parse_guess<const N: usize>(input: &str) -> Result<Word<N>, InvalidInputError> {
+    if let Some(c) = input.chars().find(|c| !c.is_ascii_alphabetic()) {
+        return Err(InvalidInputError::NotALetter(c));
+    }
+    let Ok(mut guess) = Word::<N>::try_from(input.as_bytes()) else {
+        return Err(InvalidInputError::InputLength(N));
+    };
+    guess.make_ascii_uppercase();
+    Ok(guess)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_all<const N: usize>(input: &[u8]) -> Vec<Result<Word<N>, String>> {
+        let mut input = Input::new(input);
+        let mut guesses = Vec::new();
+        while let Some(entry) = input.read_entry().unwrap() {
+            guesses.push(match entry {
+                Ok(Entry::Guess(guess)) => Ok(guess),
+                Ok(Entry::Hint) => Err("hint".to_string()),
+                Err(e) => Err(e.to_string()),
+            });
+        }
+        guesses
+    }
+
+    #[test]
+    fn test_read_guess() {
+        assert_eq!(
+            read_all::<5>(b"crane\r\n  SLOTH \nPudgy"),
+            [Ok(*b"CRANE"), Ok(*b"SLOTH"), Ok(*b"PUDGY")]
+        );
+        assert_eq!(read_all::<5>(b""), []);
+        assert_eq!(
+            read_all::<3>(b"\nten\n"),
+            [Err("Guess must have 3 characters.".into()), Ok(*b"TEN")]
+        );
+
+        let errors = read_all::<5>(b"cran\ncranes\ncaf\xc3\xa9s\ncr4ne\nbad \xff\n");
+        let expected = [
+            "Guess must have 5 characters.",
+            "Guess must have 5 characters.",
+            "expected a letter, found: 'é'",
+            "expected a letter, found: '4'",
+            "expected a letter, found: ' '",
+        ];
+        assert_eq!(errors, expected.map(|e| Err(e.to_string())));
+
+        assert_eq!(
+            read_all::<5>(b"?\n !hint\nhints\n!HINT\n"),
+            [
+                Err("hint".into()),
+                Err("hint".into()),
+                Ok(*b"HINTS"),
+                Err("expected a letter, found: '!'".into())
+            ]
+        );
+    }
+}
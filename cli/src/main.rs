@@ -1,296 +1,574 @@
-use std::fmt::{self, Write as _};
-use std::io::{stdout, Read, Write};
-
+use std::fmt;
+use std::io::{stdin, stdout, IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+use args::{Args, ArgsError, Command, USAGE};
+use code::GameCode;
+use engine::{Game, GuessError, Letters};
 use error::InvalidInputError;
-use words::Word;
-
-// TODO: let users pass in their own word lists
+use hint::{Hint, Solvers};
+use input::{Entry, Input};
+use palette::Palette;
+use save::{Origin, SavedGame};
+use stats::Stats;
+use words::score::Score;
+use words::share::ShareGrid;
+use words::{daily, suggest, Word, WordList};
+
+mod args;
+mod assist;
+mod code;
 mod error;
+mod hint;
+mod history;
+mod input;
+mod palette;
+mod save;
 mod stats;
+mod tui;
 
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
-// TODO: let users pass this in
-/// The maximum number of turns a player is allowed to take.
-const TURN_LIMIT: usize = 6;
-
 fn main() -> Result<()> {
-    let mut random_state = words::Rand::default();
-    let word = words::pick_random_word(&mut random_state);
-    let mut board = Board::new(word);
-
-    let mut won = false;
-    while !won && board.turn() < TURN_LIMIT {
-        // TODO: error handling
-        print!("Your guess: ");
-        stdout().flush()?;
-        board.input = match read_input() {
-            Ok(input) => input,
-            Err(e) => {
-                eprintln!("{}", e);
-                continue;
-            }
-        };
-        println!();
-
-        won = board.score().is_win();
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(ArgsError::Help) => {
+            println!("{USAGE}");
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("wordle: {e}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    };
 
-        println!("{}", board);
+    match args.command {
+        Command::Stats => return show_stats(),
+        Command::Replay(id) => return replay(id, &args),
+        Command::Play | Command::Assist => {}
     }
 
-    if won {
-        println!("🎉🎊🥳");
-    } else {
-        println!("Sorry, the word was {}", board.word_as_str());
+    match args.length {
+        2 => run::<2>(&args),
+        3 => run::<3>(&args),
+        4 => run::<4>(&args),
+        5 => run::<5>(&args),
+        6 => run::<6>(&args),
+        7 => run::<7>(&args),
+        8 => run::<8>(&args),
+        9 => run::<9>(&args),
+        10 => run::<10>(&args),
+        len => unreachable!("Args::parse accepted a length of {len}"),
     }
+}
 
+/// Prints the statistics of past games: those in the stats file, which predate the history, and
+/// those in the history.
+fn show_stats() -> Result<()> {
+    let (Some(path), Some(history_path)) = (stats::default_path(), history::default_path()) else {
+        eprintln!("wordle: cannot find the stats file, set $XDG_DATA_HOME or $HOME");
+        std::process::exit(1);
+    };
+    let mut stats = match Stats::load(&path) {
+        Ok(stats) => stats,
+        Err(e) => {
+            eprintln!("wordle: {}: {e}", path.display());
+            std::process::exit(1);
+        }
+    };
+    match history::load(&history_path) {
+        Ok(entries) => entries.iter().for_each(|entry| stats.record(entry)),
+        Err(e) => {
+            eprintln!("wordle: {}: {e}", history_path.display());
+            std::process::exit(1);
+        }
+    }
+    println!("{stats}");
     Ok(())
 }
 
-/// `read_input` reads one guess from stdin into `buf`. Clears `buf` in the process.
-fn read_input() -> Result<Word> {
-    // 5 letters + \n
-    let mut buf = [0u8; 6];
-    let n = std::io::stdin().read(&mut buf)?;
-
-    if n < 6 {
-        return Err(InvalidInputError::InputLength.into());
-    }
-    if *buf.last().unwrap() != b'\n' {
-        let mut stdin = std::io::stdin().lock();
-        // clear stdin for next guess
-        while !buf.contains(&b'\n') {
-            stdin.read(&mut buf)?;
+/// The delay between two turns of a replayed game.
+const REPLAY_DELAY: Duration = Duration::from_millis(800);
+
+/// Replays game number `id` of the history.
+fn replay(id: usize, args: &Args) -> Result<()> {
+    let Some(path) = history::default_path() else {
+        eprintln!("wordle: cannot find the history file, set $XDG_DATA_HOME or $HOME");
+        std::process::exit(1);
+    };
+    let entries = match history::load(&path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("wordle: {}: {e}", path.display());
+            std::process::exit(1);
         }
-        return Err(InvalidInputError::InputLength.into());
+    };
+    let Some(entry) = entries.get(id - 1) else {
+        eprintln!("wordle: there is no game {id} in {}", path.display());
+        std::process::exit(1);
+    };
+    let palette = args.palette.unwrap_or_else(Palette::detect);
+
+    match entry.length() {
+        2 => replay_game::<2>(entry, palette),
+        3 => replay_game::<3>(entry, palette),
+        4 => replay_game::<4>(entry, palette),
+        5 => replay_game::<5>(entry, palette),
+        6 => replay_game::<6>(entry, palette),
+        7 => replay_game::<7>(entry, palette),
+        8 => replay_game::<8>(entry, palette),
+        9 => replay_game::<9>(entry, palette),
+        10 => replay_game::<10>(entry, palette),
+        len => unreachable!("the history accepted a length of {len}"),
     }
+}
 
-    if let Some(&c) = buf.iter().find(|c| !c.is_ascii()) {
-        return Err(InvalidInputError::NonAscii(c).into());
+/// Prints the boards of `entry` after each turn, pausing between turns on a terminal.
+fn replay_game<const N: usize>(entry: &history::Entry, palette: Palette) -> Result<()> {
+    let to_word = |word: &String| Word::<N>::try_from(word.as_bytes()).unwrap();
+    let answers: Vec<_> = entry.answers.iter().map(to_word).collect();
+    let guesses: Vec<_> = entry.guesses.iter().map(to_word).collect();
+    // the guesses were checked when the game was played, against a word list that may be gone
+    let word_list = WordList::from_words(answers.iter().chain(&guesses).copied())
+        .expect("there is always an answer");
+    // adversarial games end on a word that gives every guess the score it got
+    let mut game = Game::new(word_list, &answers, entry.turn_limit);
+
+    let secs = entry.duration.as_secs();
+    println!(
+        "{}, played on {} in {}m {:02}s",
+        entry.origin.title(N),
+        entry.date,
+        secs / 60,
+        secs % 60
+    );
+    let pause = stdout().is_terminal();
+    for (turn, guess) in guesses.iter().enumerate() {
+        if pause && turn > 0 {
+            thread::sleep(REPLAY_DELAY);
+        }
+        game.guess(guess)?;
+        println!("\nTurn {}/{}:", turn + 1, entry.turn_limit);
+        print!("{}", render_boards(&game, palette));
+        stdout().flush()?;
     }
 
-    // ok to unwrap here, size has been asserted
-    let mut guess = Word::try_from(&buf[..5]).unwrap();
-    guess.make_ascii_uppercase();
-
-    Ok(guess)
+    println!();
+    if game.is_won() {
+        println!("Solved in {}/{}.", game.turn(), game.turn_limit());
+    } else {
+        println!("Not solved, the answer was {}.", game.words_as_str());
+    }
+    Ok(())
 }
 
-/// The score of a single letter.
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-#[repr(u8)]
-enum LetterScore {
-    /// A letter that is not in the word at all.
-    #[default]
-    Wrong = 0,
-    /// A letter that is in the word but at a different spot.
-    InWord = 1,
-    /// A letter both in the word and at the right spot.
-    Right = 2,
+/// Runs `args.command` with words of `N` letters.
+fn run<const N: usize>(args: &Args) -> Result<()> {
+    let word_list = match &args.words {
+        Some(path) => match WordList::<N>::from_path(path) {
+            Ok(list) => list,
+            Err(e) => {
+                eprintln!("wordle: {}: {e}", path.display());
+                std::process::exit(1);
+            }
+        },
+        None => match WordList::builtin() {
+            Some(list) => list,
+            None => {
+                eprintln!("wordle: there is no built-in list of {N}-letter words, use --words");
+                std::process::exit(1);
+            }
+        },
+    };
+    let palette = args.palette.unwrap_or_else(Palette::detect);
+
+    match args.command {
+        Command::Play => play(args, word_list, palette),
+        Command::Assist => Ok(assist::run(&word_list, palette)?),
+        Command::Stats | Command::Replay(_) => {
+            unreachable!("stats and replays don't depend on the word length")
+        }
+    }
 }
 
-impl LetterScore {
-    const ALL: [Self; 3] = [Self::Wrong, Self::InWord, Self::Right];
-
-    // this is obviously unfortunate compared to just assigning the variants directly
-    // but should the variants ever need special values this will come in handy.
-    #[cfg(test)]
-    const fn variant(self) -> u8 {
-        self as u8
+/// Plays one game with a word from `word_list`, or resumes a saved one, see [`resume`].
+fn play<const N: usize>(args: &Args, word_list: WordList<N>, palette: Palette) -> Result<()> {
+    let resumed = resume(args, &word_list);
+    let is_resumed = resumed.is_some();
+    let mut saved = resumed.unwrap_or_else(|| new_game(args, word_list));
+    let game = &saved.game;
+    if let Origin::Daily(puzzle) = saved.origin {
+        println!("Wordle #{puzzle} ({})\n", daily::puzzle_date(puzzle));
+        if game.is_over() {
+            println!("You already played this puzzle.\n");
+            print!("{}", render_boards(game, palette));
+            return Ok(());
+        }
+    }
+    if is_resumed && !args.tui {
+        println!("{}", render_boards(game, palette));
     }
+    // without a saved game, quitting is the only way to find out the word
+    let is_saved = match saved.autosave() {
+        Ok(()) => saved.origin.path(N).is_some(),
+        Err(e) => {
+            eprintln!("wordle: failed to save the game: {e}");
+            false
+        }
+    };
 
-    const fn bg_color(self) -> u8 {
-        match self {
-            Self::Wrong => 100,
-            Self::InWord => 43,
-            Self::Right => 42,
+    let finished = if args.tui {
+        tui::play(&mut saved, palette)?
+    } else {
+        play_lines(&mut saved, palette)?
+    };
+    let game = &saved.game;
+    let word_was = match game.boards().len() {
+        1 => "word was",
+        _ => "words were",
+    };
+    let words = game.words_as_str();
+    if !finished {
+        if is_saved {
+            println!("\nYour game is saved, run wordle again to resume it.");
+        } else {
+            println!("\nThe {word_was} {words}");
         }
+        return Ok(());
     }
-}
-
-/// Renders `word` to `w` given `score`. Uses ANSI escapes to color the letters.
-fn render(mut w: impl fmt::Write, word: &Word, score: Score) -> fmt::Result {
-    for (i, c) in word.iter().enumerate() {
-        let color = score.get(i).bg_color();
-        write!(w, "\x1b[30;{color}m{0}", *c as char)?;
+    if args.tui {
+        // the full-screen board is gone once the terminal is restored
+        println!("{}", render_boards(game, palette));
     }
-    write!(w, "\x1b[m")?;
-    Ok(())
-}
-
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
-struct Score(u8);
-
-impl Score {
-    const PERFECT: Self = Score(242);
 
-    fn is_win(self) -> bool {
-        self == Self::PERFECT
+    let won = game.is_won();
+    if won {
+        println!("🎉🎊🥳");
+    } else {
+        println!("Sorry, the {word_was} {words}");
     }
 
-    fn set(&mut self, at: usize, score: LetterScore) {
-        let pos = 3u8.pow(at as u32);
-        self.0 += score as u8 * pos;
+    // random games are named after their code, so they can be replayed from the shared result
+    if let Origin::Seed { seed, boards } = saved.origin {
+        let code = GameCode {
+            seed,
+            length: N,
+            boards,
+        };
+        println!("Seed: {seed} (game code: {code})");
+    }
+    let title = saved.origin.title(N);
+    for (i, board) in game.boards().iter().enumerate() {
+        let title = match game.boards().len() {
+            1 => title.clone(),
+            n => format!("{title} ({}/{n})", i + 1),
+        };
+        let scores: Vec<_> = board.guesses().iter().map(|&(_, score)| score).collect();
+        let share = ShareGrid {
+            title: &title,
+            scores: &scores,
+            turn_limit: game.turn_limit(),
+            hard: game.is_hard(),
+            hints: game.hints(),
+            theme: args.theme,
+        };
+        println!("\n{share}");
     }
 
-    fn get(self, at: usize) -> LetterScore {
-        let pos = 3u8.pow(at as u32);
-        LetterScore::ALL[(self.0 / pos) as usize % 3]
+    record_game(&saved);
+    if let Err(e) = saved.clear() {
+        eprintln!("wordle: failed to remove the saved game: {e}");
     }
+
+    Ok(())
+}
+
+/// Starts the game `args` asks for.
+fn new_game<const N: usize>(args: &Args, word_list: WordList<N>) -> SavedGame<N> {
+    let turn_limit = args.turn_limit();
+    let (origin, game) = if args.adversarial {
+        (
+            Origin::Adversarial,
+            Game::adversarial(word_list, turn_limit),
+        )
+    } else if let Some(puzzle) = args.puzzle {
+        let answers = [word_list.daily(puzzle)];
+        (
+            Origin::Daily(puzzle),
+            Game::new(word_list, &answers, turn_limit),
+        )
+    } else {
+        let seed = args.seed.unwrap_or_else(words::Rand::random_seed);
+        let mut random_state = words::Rand::new(seed);
+        let answers = pick_words(&word_list, args.boards, &mut random_state);
+        (
+            Origin::Seed {
+                seed,
+                boards: args.boards,
+            },
+            Game::new(word_list, &answers, turn_limit),
+        )
+    };
+    SavedGame::new(origin, game.with_hard_mode(args.hard))
 }
 
-fn score(word: &Word, guess: &Word) -> Score {
-    if word == guess {
-        return Score::PERFECT;
+/// Returns the saved game to continue instead of starting the one `args` asks for, if any.
+///
+/// A daily puzzle that was started before is always continued, or shown if it's over. Any other
+/// unfinished game is only continued if the player wants to, which requires stdin to be a
+/// terminal.
+fn resume<const N: usize>(args: &Args, word_list: &WordList<N>) -> Option<SavedGame<N>> {
+    let path = match args.puzzle {
+        Some(puzzle) => Origin::Daily(puzzle).path(N),
+        // random and adversarial games share a file
+        None => Origin::Adversarial.path(N),
+    }?;
+    let saved = match SavedGame::load(&path, word_list.clone()) {
+        Ok(saved) => saved?,
+        Err(e) => {
+            eprintln!("wordle: ignoring the saved game in {}: {e}", path.display());
+            return None;
+        }
+    };
+    if let Some(puzzle) = args.puzzle {
+        return (saved.origin == Origin::Daily(puzzle)).then_some(saved);
+    }
+    if saved.game.is_over() || !stdin().is_terminal() {
+        return None;
     }
 
-    // invalid ascii byte to use as a placeholder
-    const SENTINEL: u8 = u8::MAX;
-    let mut score = Score::default();
-    let mut word = *word;
+    print!(
+        "Resume your unfinished game ({}, {} of {} guesses made)? [Y/n] ",
+        saved.origin.title(N),
+        saved.game.turn(),
+        saved.game.turn_limit()
+    );
+    stdout().flush().ok()?;
+    let mut answer = String::new();
+    stdin().read_line(&mut answer).ok()?;
+    println!();
+    matches!(&*answer.trim().to_ascii_lowercase(), "" | "y" | "yes").then_some(saved)
+}
 
-    // find correct letters
-    for (i, (w, g)) in word.iter_mut().zip(guess).enumerate() {
-        if w == g {
-            score.set(i, LetterScore::Right);
-            *w = SENTINEL;
+/// Picks `count` different words from `word_list`, or as many as it has.
+fn pick_words<const N: usize>(
+    word_list: &WordList<N>,
+    count: usize,
+    random_state: &mut words::Rand,
+) -> Vec<Word<N>> {
+    let count = count.min(word_list.len());
+    let mut picked = Vec::with_capacity(count);
+    while picked.len() < count {
+        let word = word_list.pick_random(random_state);
+        if !picked.contains(&word) {
+            picked.push(word);
         }
     }
+    picked
+}
 
-    for (i, g) in guess.iter().enumerate() {
-        if score.get(i) == LetterScore::Right {
-            continue;
+/// Plays the game of `saved`, reading one guess per line from stdin and saving the game after
+/// each of them. Returns `false` if the input ended before the game did.
+fn play_lines<const N: usize>(saved: &mut SavedGame<N>, palette: Palette) -> Result<bool> {
+    let mut input = Input::new(stdin().lock());
+    let mut solvers = Solvers::new(hint::matrix_path());
+    while !saved.game.is_over() {
+        print!("Your guess: ");
+        stdout().flush()?;
+        match input.read_entry()? {
+            Some(Ok(Entry::Guess(guess))) => {
+                if let Err(e) = play_guess(&mut saved.game, &guess) {
+                    eprintln!("{}", e);
+                    continue;
+                }
+                println!();
+
+                let game = &saved.game;
+                println!("{}", render_boards(game, palette));
+                let mut keyboard = String::new();
+                render_keyboard(&mut keyboard, &game.letters(), palette).expect("OOM");
+                println!("{keyboard}");
+            }
+            Some(Ok(Entry::Hint)) => println!("{}", next_hint(&mut saved.game, &mut solvers)),
+            Some(Err(e)) => {
+                eprintln!("{}", e);
+                continue;
+            }
+            None => {
+                println!();
+                return Ok(false);
+            }
         }
-        if let Some(w) = word.iter_mut().find(|w| *w == g) {
-            score.set(i, LetterScore::InWord);
-            *w = SENTINEL;
+        if let Err(e) = saved.autosave() {
+            eprintln!("wordle: failed to save the game: {e}");
         }
     }
 
-    score
+    Ok(true)
 }
 
-#[derive(Default)]
-struct Board {
-    word: Word,
-    input: Word,
-    guesses: [(Word, Score); TURN_LIMIT],
-    turn: usize,
-}
-
-impl Board {
-    fn new(word: Word) -> Self {
-        Self {
-            word,
-            ..Default::default()
+/// Plays `guess` in `game`, which must not be over, turning the reasons it may be rejected into
+/// errors to show the player.
+fn play_guess<const N: usize>(
+    game: &mut Game<N>,
+    guess: &Word<N>,
+) -> std::result::Result<(), InvalidInputError> {
+    match game.guess(guess) {
+        Ok(_) => Ok(()),
+        Err(GuessError::NotAWord) => {
+            let suggestion = suggest::closest(guess, game.word_list().words());
+            Err(InvalidInputError::NotAWord(
+                words::to_str(guess).to_string(),
+                suggestion.map(|word| words::to_str(word).to_string()),
+            ))
         }
+        Err(GuessError::HardMode(violation)) => Err(InvalidInputError::HardMode(violation)),
+        Err(GuessError::GameOver) => unreachable!("guesses are only read until the game is over"),
     }
+}
 
-    fn score(&mut self) -> Score {
-        let score = score(&self.word, &self.input);
-        self.guesses[self.turn] = (self.input, score);
-        self.turn += 1;
-        score
-    }
+/// Returns the next hint for the first board of `game` that isn't solved yet, see
+/// [`hint::next`], and records it in `game`. Hints don't use up a turn.
+fn next_hint<const N: usize>(game: &mut Game<N>, solvers: &mut Solvers<N>) -> Hint<N> {
+    let index = game
+        .boards()
+        .iter()
+        .position(|board| !board.is_won())
+        .expect("the game is not over");
+    let solver = solvers.get(game, index);
+    let hint = hint::next(&game.boards()[index], game.hints(), solver);
+    game.record_hint();
+    hint
+}
 
-    fn word_as_str(&self) -> &str {
-        std::str::from_utf8(&self.word).unwrap()
+/// Appends the finished game of `saved` to the history and tells the player how to replay it.
+/// Failing to do so is reported but not fatal, the game is already over after all.
+fn record_game<const N: usize>(saved: &SavedGame<N>) {
+    let Some(path) = history::default_path() else {
+        return;
+    };
+    let entry = history::Entry::new(&saved.game, saved.origin, saved.elapsed());
+    match history::append(&path, &entry) {
+        Ok(id) => println!("Replay this game with: wordle replay {id}"),
+        Err(e) => eprintln!(
+            "wordle: failed to record the game in {}: {e}",
+            path.display()
+        ),
     }
+}
 
-    fn turn(&self) -> usize {
-        self.turn
+/// Renders `word` to `w` given `score`, drawing the letters with `palette`.
+fn render<const N: usize>(
+    mut w: impl fmt::Write,
+    word: &Word<N>,
+    score: Score<N>,
+    palette: Palette,
+) -> fmt::Result {
+    for (c, letter) in word.iter().zip(score) {
+        palette.write_letter(&mut w, *c as char, Some(letter), false)?;
     }
+    Ok(())
 }
 
-impl fmt::Display for Board {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (word, score) in &self.guesses[0..self.turn] {
-            render(&mut *f, word, *score).expect("OOM");
-            f.write_char('\n')?;
+/// Renders the letters A to Z to `w` in keyboard order, drawn like [`render`] by their best known
+/// score. Letters that weren't guessed yet are drawn plain.
+fn render_keyboard(mut w: impl fmt::Write, letters: &Letters, palette: Palette) -> fmt::Result {
+    for (indent, row) in KEYBOARD.iter().enumerate() {
+        write!(w, "{:indent$}", "")?;
+        for c in row.bytes() {
+            palette.write_letter(&mut w, c as char, letters[(c - b'A') as usize], false)?;
         }
+        w.write_char('\n')?;
+    }
+    Ok(())
+}
+
+/// The rows of a QWERTY keyboard.
+const KEYBOARD: [&str; 3] = ["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"];
+
+/// The most boards drawn next to each other, more are drawn below.
+const BOARDS_PER_ROW: usize = 4;
 
-        Ok(())
+/// Renders the boards of `game` side by side, [`BOARDS_PER_ROW`] at a time, drawing the letters
+/// with `palette`.
+fn render_boards<const N: usize>(game: &Game<N>, palette: Palette) -> String {
+    let mut f = String::new();
+    for (i, boards) in game.boards().chunks(BOARDS_PER_ROW).enumerate() {
+        if i > 0 {
+            f.push('\n');
+        }
+        let rows = boards.iter().map(|b| b.guesses().len()).max().unwrap_or(0);
+        for row in 0..rows {
+            for (j, board) in boards.iter().enumerate() {
+                if j > 0 {
+                    f.push_str("  ");
+                }
+                match board.guesses().get(row) {
+                    Some((word, score)) => render(&mut f, word, *score, palette).expect("OOM"),
+                    // solved earlier, keep the space so the boards to the right line up
+                    None if j + 1 < boards.len() => {
+                        for _ in 0..N {
+                            palette.write_letter(&mut f, ' ', None, false).expect("OOM");
+                        }
+                    }
+                    None => {}
+                }
+            }
+            f.push('\n');
+        }
     }
+    f
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use words::score::LetterScore;
+
+    fn game(answers: &[Word]) -> Game {
+        let word_list = WordList::from_reader(&b"CIGAR\nREBUT\nSISSY\nHUMPH\nCLOSE"[..]).unwrap();
+        Game::new(word_list, answers, 6)
+    }
 
     #[test]
-    fn test_score() {
-        use LetterScore::*;
-        let table = [
-            (
-                b"words",
-                b"birds",
-                [Wrong, Wrong, Right, Right, Right].into(),
-            ),
-            (
-                b"tests",
-                b"stabs",
-                [InWord, InWord, Wrong, Wrong, Right].into(),
-            ),
-            (b"cargo", b"gocar", [InWord; 5].into()),
-            (b"cargo", b"cargo", [Right; 5].into()),
-            (
-                b"stark",
-                b"lossy",
-                [Wrong, Wrong, InWord, Wrong, Wrong].into(),
-            ),
-            (
-                b"liege",
-                b"liens",
-                [Right, Right, Right, Wrong, Wrong].into(),
-            ),
-            (
-                b"liege",
-                b"litre",
-                [Right, Right, Wrong, Wrong, Right].into(),
-            ),
-            (
-                b"abcde",
-                b"edcba",
-                [InWord, InWord, Right, InWord, InWord].into(),
-            ),
-            (
-                b"abcde",
-                b"ccccc",
-                [Wrong, Wrong, Right, Wrong, Wrong].into(),
-            ),
-            (
-                b"abcde",
-                b"ccxxx",
-                [InWord, Wrong, Wrong, Wrong, Wrong].into(),
-            ),
-        ];
-
-        for (w, g, expected) in table {
-            let got = score(w, g);
-            assert_eq!(got, expected);
-            // assert_eq!(got, decompress(compress(&got)));
+    fn test_keyboard() {
+        let mut game = game(&[*b"CLOSE"]);
+        for word in [b"CRANE", b"CLOSE"] {
+            game.guess(word).unwrap();
         }
-    }
+        let letters = game.letters();
+        assert_eq!(letters[(b'E' - b'A') as usize], Some(LetterScore::Right));
 
-    type ScoreArray = [LetterScore; 5];
+        let mut keyboard = String::new();
+        render_keyboard(&mut keyboard, &letters, Palette::Classic).unwrap();
+        assert!(keyboard.starts_with("QW\x1b[30;42mE\x1b[m\x1b[30;100mR\x1b[mTYUI"));
+        assert!(keyboard.ends_with("\n  ZX\x1b[30;42mC\x1b[mVB\x1b[30;100mN\x1b[mM\n"));
+    }
 
-    impl From<ScoreArray> for Score {
-        fn from(value: ScoreArray) -> Self {
-            Score(compress(&value))
-        }
+    #[test]
+    fn test_boards() {
+        let mut game = game(&[*b"CLOSE", *b"CIGAR"]);
+        game.guess(b"CLOSE").unwrap();
+        game.guess(b"CIGAR").unwrap();
+        assert_eq!(
+            render_boards(&game, Palette::Mono),
+            "[C][L][O][S][E]  [C] l  o  s  e \n                 [C][I][G][A][R]\n"
+        );
     }
 
-    /// `compress` compresses a `Score` into a single byte.
-    /// This works out because log2(3^5) < 8.
-    ///
-    /// [`decompress`] reverses this process again.
-    #[allow(unused)]
-    fn compress(score: &ScoreArray) -> u8 {
-        let mut compressed = 0;
-        for s in score.iter().rev() {
-            compressed *= 3;
-            compressed += s.variant();
-        }
-        compressed
+    #[test]
+    fn test_play_guess() {
+        let mut game = game(&[*b"CLOSE"]).with_hard_mode(true);
+        assert_eq!(
+            play_guess(&mut game, b"CLOSX").unwrap_err().to_string(),
+            "CLOSX is not a word. Did you mean CLOSE?"
+        );
+        play_guess(&mut game, b"CIGAR").unwrap();
+        assert!(matches!(
+            play_guess(&mut game, b"HUMPH"),
+            Err(InvalidInputError::HardMode(_))
+        ));
+        assert_eq!(game.turn(), 1);
     }
 }
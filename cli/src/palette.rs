@@ -0,0 +1,110 @@
+//! How scored letters are drawn in the terminal.
+use std::env;
+use std::fmt;
+use std::io::{stdout, IsTerminal};
+use std::str::FromStr;
+
+use words::score::LetterScore;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Palette {
+    /// Gray, yellow and green backgrounds.
+    #[default]
+    Classic,
+    /// Gray, blue and orange backgrounds, which are easier to tell apart for colorblind players.
+    HighContrast,
+    /// No colors at all. Right letters are drawn as `[A]`, letters in the word as `(A)` and wrong
+    /// letters in lowercase, as ` a `.
+    Mono,
+}
+
+impl Palette {
+    /// Returns the palette to use if the player didn't pick one: [`Palette::Mono`] if the
+    /// `NO_COLOR` environment variable is set or stdout is not a terminal, so that pipes and logs
+    /// don't fill up with escape codes, [`Palette::Classic`] otherwise.
+    pub fn detect() -> Self {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        if no_color || !stdout().is_terminal() {
+            Self::Mono
+        } else {
+            Self::Classic
+        }
+    }
+
+    /// Returns the SGR parameters of the background `score` is drawn on.
+    const fn background(self, score: LetterScore) -> Option<&'static str> {
+        match (self, score) {
+            (Self::Mono, _) => None,
+            (_, LetterScore::Wrong) => Some("100"),
+            (Self::Classic, LetterScore::InWord) => Some("43"),
+            (Self::Classic, LetterScore::Right) => Some("42"),
+            (Self::HighContrast, LetterScore::InWord) => Some("48;5;33"),
+            (Self::HighContrast, LetterScore::Right) => Some("48;5;208"),
+        }
+    }
+
+    /// Writes `c` as scored by `score`, or as a plain letter if `score` is `None`.
+    ///
+    /// Colored letters take up a single column, unless `pad` is set, in which case they get a
+    /// space on either side. Monochrome letters always take up three columns.
+    pub fn write_letter(
+        self,
+        mut w: impl fmt::Write,
+        c: char,
+        score: Option<LetterScore>,
+        pad: bool,
+    ) -> fmt::Result {
+        let pad = if pad || self == Self::Mono { " " } else { "" };
+        match (score, self.background(score.unwrap_or_default())) {
+            (None, _) => write!(w, "{pad}{c}{pad}"),
+            (Some(_), Some(bg)) => write!(w, "\x1b[30;{bg}m{pad}{c}{pad}\x1b[m"),
+            (Some(LetterScore::Right), None) => write!(w, "[{c}]"),
+            (Some(LetterScore::InWord), None) => write!(w, "({c})"),
+            (Some(LetterScore::Wrong), None) => write!(w, " {} ", c.to_ascii_lowercase()),
+        }
+    }
+}
+
+impl FromStr for Palette {
+    type Err = InvalidPalette;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classic" => Ok(Self::Classic),
+            "high-contrast" => Ok(Self::HighContrast),
+            "mono" => Ok(Self::Mono),
+            _ => Err(InvalidPalette),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidPalette;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use LetterScore::*;
+
+    fn letters(palette: Palette, pad: bool) -> String {
+        let mut s = String::new();
+        for score in [Some(Right), Some(InWord), Some(Wrong), None] {
+            palette.write_letter(&mut s, 'A', score, pad).unwrap();
+        }
+        s
+    }
+
+    #[test]
+    fn test_write_letter() {
+        assert_eq!(
+            letters(Palette::Classic, false),
+            "\x1b[30;42mA\x1b[m\x1b[30;43mA\x1b[m\x1b[30;100mA\x1b[mA"
+        );
+        assert_eq!(
+            letters(Palette::HighContrast, true),
+            "\x1b[30;48;5;208m A \x1b[m\x1b[30;48;5;33m A \x1b[m\x1b[30;100m A \x1b[m A "
+        );
+        assert_eq!(letters(Palette::Mono, false), "[A](A) a  A ");
+        assert_eq!(letters(Palette::Mono, true), letters(Palette::Mono, false));
+    }
+}
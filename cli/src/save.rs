@@ -0,0 +1,367 @@
+//! Saving games in progress, so they can be resumed after quitting.
+//!
+//! The game is saved after every guess. Every daily puzzle is saved in a file of its own and kept
+//! once it is over, so a puzzle can't be started over to get another go at it. The
+//! answers are obfuscated, so a glance at the file doesn't give them away, but this is no
+//! protection against anyone set on reading them.
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use engine::Game;
+use words::{file, WordList};
+
+use crate::args::MAX_BOARDS;
+use crate::code::GameCode;
+use crate::stats;
+
+/// The version of the format written by [`SavedGame::serialize`].
+const VERSION: u32 = 2;
+
+/// The key of the stream the game is XORed with, see [`obfuscate`].
+const KEY: u64 = 0x5745_4c4c_444f_4e45;
+
+/// How a game was started, which decides its title and where it is saved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Origin {
+    /// The daily puzzle with this number.
+    Daily(u32),
+    /// A random game of `boards` words picked with `seed`.
+    Seed { seed: u64, boards: usize },
+    /// A game against an adversary, see [`words::adversary`].
+    Adversarial,
+}
+
+impl Origin {
+    /// Returns the title results of a game with words of `length` letters are shared with.
+    pub fn title(self, length: usize) -> String {
+        match self {
+            Self::Daily(puzzle) => format!("Wordle {puzzle}"),
+            Self::Seed { seed, boards } => {
+                let code = GameCode {
+                    seed,
+                    length,
+                    boards,
+                };
+                format!("Wordle {code}")
+            }
+            Self::Adversarial => "Wordle adversarial".to_string(),
+        }
+    }
+
+    /// Returns the file games of this origin with words of `length` letters are saved in, see
+    /// [`Origin::path_in`].
+    pub fn path(self, length: usize) -> Option<PathBuf> {
+        Some(self.path_in(&stats::data_dir()?, length))
+    }
+
+    /// Returns the file in `dir` games of this origin with words of `length` letters are saved
+    /// in: `daily-<length>-<puzzle>` for a daily puzzle, and `game-<length>` for the last of the
+    /// other games. Games of another length never replace each other.
+    pub fn path_in(self, dir: &Path, length: usize) -> PathBuf {
+        match self {
+            Self::Daily(puzzle) => dir.join(format!("daily-{length}-{puzzle}")),
+            Self::Seed { .. } | Self::Adversarial => dir.join(format!("game-{length}")),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Daily(puzzle) => write!(f, "daily {puzzle}"),
+            Self::Seed { seed, boards: 1 } => write!(f, "seed {seed}"),
+            Self::Seed { seed, boards } => write!(f, "seed {seed} {boards}"),
+            Self::Adversarial => write!(f, "adversarial"),
+        }
+    }
+}
+
+impl FromStr for Origin {
+    type Err = InvalidOrigin;
+
+    /// Parses an origin as written by [`Origin`]'s `Display` impl, e.g. `seed 42`, or `seed 42 4`
+    /// for a game of 4 boards.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(' ') {
+            Some(("daily", puzzle)) => puzzle.parse().map(Self::Daily).map_err(|_| InvalidOrigin),
+            Some(("seed", seed)) => {
+                let (seed, boards) = seed.split_once(' ').unwrap_or((seed, "1"));
+                match (seed.parse(), boards.parse()) {
+                    (Ok(seed), Ok(boards)) if (1..=MAX_BOARDS).contains(&boards) => {
+                        Ok(Self::Seed { seed, boards })
+                    }
+                    _ => Err(InvalidOrigin),
+                }
+            }
+            _ if s == "adversarial" => Ok(Self::Adversarial),
+            _ => Err(InvalidOrigin),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidOrigin;
+
+impl Error for InvalidOrigin {}
+
+impl fmt::Display for InvalidOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid game origin")
+    }
+}
+
+/// A game together with how it was started.
+#[derive(Clone, Debug)]
+pub struct SavedGame<const N: usize> {
+    pub origin: Origin,
+    pub game: Game<N>,
+    /// The time spent playing before the game was loaded.
+    elapsed: Duration,
+    /// When the game was started or loaded.
+    started: Instant,
+}
+
+impl<const N: usize> SavedGame<N> {
+    /// Starts keeping track of a new game.
+    pub fn new(origin: Origin, game: Game<N>) -> Self {
+        Self::resumed(origin, game, Duration::ZERO)
+    }
+
+    fn resumed(origin: Origin, game: Game<N>, elapsed: Duration) -> Self {
+        Self {
+            origin,
+            game,
+            elapsed,
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the time spent playing, including before the game was saved and resumed.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed + self.started.elapsed()
+    }
+
+    /// Reads the game saved at `path`, whose guesses must be allowed by `word_list`. Returns
+    /// `None` if there is none or it has words of another length.
+    pub fn load(path: &Path, word_list: WordList<N>) -> Result<Option<Self>, Box<dyn Error>> {
+        file::load_or_default(path, |file| Self::deserialize(file, word_list))
+    }
+
+    /// Writes this game to `path`, replacing the game saved there before, see
+    /// [`file::write_atomic`].
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        file::write_atomic(path, |w| self.serialize(w))
+    }
+
+    /// Saves the game to the file of its origin, if there is one.
+    pub fn autosave(&self) -> Result<(), Box<dyn Error>> {
+        match self.origin.path(N) {
+            Some(path) => self.save(&path),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes the saved game once it is over. Daily puzzles are kept, so they can't be played
+    /// again.
+    pub fn clear(&self) -> Result<(), Box<dyn Error>> {
+        let (false, Some(path)) = (matches!(self.origin, Origin::Daily(_)), self.origin.path(N))
+        else {
+            return Ok(());
+        };
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn serialize(&self, mut w: impl Write) -> Result<(), Box<dyn Error>> {
+        writeln!(w, "v{VERSION}")?;
+        writeln!(w, "length {N}")?;
+        writeln!(w, "{}", self.origin)?;
+        writeln!(w, "elapsed {}", self.elapsed().as_secs())?;
+        let mut game = Vec::new();
+        self.game.serialize(&mut game)?;
+        obfuscate(&mut game);
+        let mut hex = String::with_capacity(game.len() * 2);
+        for byte in game {
+            write!(hex, "{byte:02x}").expect("OOM");
+        }
+        writeln!(w, "{hex}")?;
+        Ok(())
+    }
+
+    /// Reads a game written by [`SavedGame::serialize`], see [`SavedGame::load`]. The time spent
+    /// playing wasn't recorded before version 2, so it starts out at 0 then.
+    pub fn deserialize(
+        r: impl Read,
+        word_list: WordList<N>,
+    ) -> Result<Option<Self>, Box<dyn Error>> {
+        let buf = file::read_capped(r)?;
+
+        let mut lines = buf.lines();
+        let mut next_line = || lines.next().ok_or("unexpected end of saved game");
+        let version = match next_line()?.strip_prefix('v') {
+            Some(version @ ("1" | "2")) => version,
+            _ => return Err("unsupported saved game version".into()),
+        };
+        let length = next_line()?
+            .strip_prefix("length ")
+            .and_then(|length| length.parse::<usize>().ok())
+            .ok_or("invalid word length")?;
+        if length != N {
+            return Ok(None);
+        }
+        let origin = next_line()?.parse()?;
+        let elapsed = match version {
+            "1" => Duration::ZERO,
+            _ => next_line()?
+                .strip_prefix("elapsed ")
+                .and_then(|secs| secs.parse().ok())
+                .map(Duration::from_secs)
+                .ok_or("invalid time spent playing")?,
+        };
+
+        let hex = next_line()?;
+        if hex.len() % 2 != 0 {
+            return Err("invalid game data".into());
+        }
+        let mut game = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<_>>>()
+            .ok_or("invalid game data")?;
+        obfuscate(&mut game);
+        let game = Game::deserialize(&game[..], word_list)?;
+        Ok(Some(Self::resumed(origin, game, elapsed)))
+    }
+}
+
+/// XORs `data` with a fixed pseudo-random stream. Applying it twice gives back `data`.
+fn obfuscate(data: &mut [u8]) {
+    let mut state = KEY;
+    for byte in data {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        *byte ^= state as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn word_list() -> WordList {
+        WordList::from_reader(&b"CIGAR\nREBUT\nSISSY\nHUMPH\nCLOSE"[..]).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for origin in [
+            Origin::Daily(100),
+            Origin::Seed {
+                seed: 42,
+                boards: 2,
+            },
+            Origin::Adversarial,
+        ] {
+            let mut game = match origin {
+                Origin::Adversarial => Game::adversarial(word_list(), 20),
+                _ => Game::new(word_list(), &[*b"HUMPH", *b"SISSY"], 7),
+            };
+            game.guess(b"CRANE").unwrap();
+            let saved = SavedGame::resumed(origin, game, Duration::from_secs(90));
+
+            let mut buf = Vec::new();
+            saved.serialize(&mut buf).unwrap();
+            let text = String::from_utf8(buf.clone()).unwrap();
+            assert!(!text.contains("HUMPH") && !text.contains("CRANE"), "{text}");
+
+            let des = SavedGame::deserialize(&buf[..], word_list())
+                .unwrap()
+                .unwrap();
+            assert_eq!(des.origin, origin);
+            assert_eq!(des.elapsed().as_secs(), 90);
+            assert_eq!(des.game.words_as_str(), saved.game.words_as_str());
+            assert_eq!(des.game.history(), saved.game.history());
+
+            // the time spent playing wasn't saved before version 2
+            let v1 = text.replacen("v2\n", "v1\n", 1).replace("elapsed 90\n", "");
+            let des = SavedGame::deserialize(v1.as_bytes(), word_list())
+                .unwrap()
+                .unwrap();
+            assert_eq!(des.elapsed().as_secs(), 0);
+            assert_eq!(des.game.history(), saved.game.history());
+
+            // a game with words of another length is not for this one to resume
+            let word_list = WordList::from_reader(&b"ABLE"[..]).unwrap();
+            let other = SavedGame::<4>::deserialize(&buf[..], word_list).unwrap();
+            assert!(other.is_none());
+        }
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        for invalid in [
+            "",
+            "v3\nlength 5\nseed 1\nelapsed 0\n00\n",
+            "v1\nlength 5\nrandom\n00\n",
+            "v1\nlength 5\nseed 1 9\n00\n",
+            "v2\nlength 5\nseed 1\n00\n",
+            "v1\nlength 5\nseed 1\n0\n",
+            "v1\nlength 5\nseed 1\nzz\n",
+            // valid hex, but not a game once deobfuscated
+            "v1\nlength 5\nseed 1\n0000\n",
+        ] {
+            assert!(
+                SavedGame::deserialize(invalid.as_bytes(), word_list()).is_err(),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_daily_path() {
+        let dir = env::temp_dir().join(format!("wordle-save-{}", std::process::id()));
+        let today = Origin::Daily(100).path_in(&dir, 5);
+
+        // today's puzzle is finished, then other games are played
+        let mut game = Game::new(word_list(), &[*b"CLOSE"], 6);
+        game.guess(b"CLOSE").unwrap();
+        SavedGame::new(Origin::Daily(100), game)
+            .save(&today)
+            .unwrap();
+        for origin in [
+            Origin::Daily(3),
+            Origin::Seed {
+                seed: 42,
+                boards: 1,
+            },
+        ] {
+            let game = Game::new(word_list(), &[*b"CIGAR"], 6);
+            SavedGame::new(origin, game)
+                .save(&origin.path_in(&dir, 5))
+                .unwrap();
+        }
+        // the same puzzle, with words of another length
+        let four_letters = WordList::from_reader(&b"ABLE"[..]).unwrap();
+        let origin = Origin::Daily(100);
+        SavedGame::new(origin, Game::new(four_letters, &[*b"ABLE"], 6))
+            .save(&origin.path_in(&dir, 4))
+            .unwrap();
+
+        let saved = SavedGame::load(&today, word_list()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        let saved = saved.unwrap();
+        assert_eq!(saved.origin, Origin::Daily(100));
+        assert!(saved.game.is_won());
+    }
+}
@@ -1,72 +1,299 @@
 use std::{
+    collections::BTreeMap,
+    env,
     error::Error,
     fmt,
-    io::{Read, Write},
+    io::Read,
+    path::{Path, PathBuf},
 };
 
-// repr(C) for stable ABI
-#[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+use words::file;
+
+use crate::history::Entry;
+
+/// Returns the directory the CLI keeps its data in: `$XDG_DATA_HOME/wordle`, falling back to
+/// `$HOME/.local/share/wordle`. Returns `None` if neither variable is set.
+pub fn data_dir() -> Option<PathBuf> {
+    let data_home = match env::var_os("XDG_DATA_HOME") {
+        // the spec says relative paths are invalid and should be ignored
+        Some(dir) if Path::new(&dir).is_absolute() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".local/share"),
+    };
+    Some(data_home.join("wordle"))
+}
+
+/// Returns the file the stats of games played before the [history](crate::history) was kept are
+/// stored in: `stats` in [`data_dir`]. It is no longer written, finished games are counted from
+/// the history on top of it instead.
+pub fn default_path() -> Option<PathBuf> {
+    Some(data_dir()?.join("stats"))
+}
+
+/// The turn limit of stats written before it was configurable.
+const LEGACY_TURN_LIMIT: usize = 6;
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Stats {
-    wins: [u32; 6],
+    /// The results of the games played with each turn limit.
+    histograms: BTreeMap<usize, Histogram>,
+    /// The number of games won in a row, up to the last one.
+    current_streak: u32,
+    /// The longest streak so far.
+    max_streak: u32,
+    /// The number of games in which hints were used.
+    hinted: u32,
+}
+
+/// The results of the games played with the same turn limit.
+#[derive(Clone, Debug, PartialEq)]
+struct Histogram {
+    /// The number of games won in each round, one entry per turn.
+    wins: Vec<u32>,
     losses: u32,
 }
 
+impl Histogram {
+    fn new(turn_limit: usize) -> Self {
+        Self {
+            wins: vec![0; turn_limit],
+            losses: 0,
+        }
+    }
+
+    /// Creates a histogram from stats written before the turn limit was configurable.
+    fn legacy(wins: [u32; LEGACY_TURN_LIMIT], losses: u32) -> Self {
+        Self {
+            wins: wins.to_vec(),
+            losses,
+        }
+    }
+
+    fn games_won(&self) -> u64 {
+        self.wins.iter().map(|&n| n as u64).sum()
+    }
+}
+
 impl Stats {
-    pub fn record_win(&mut self, round: usize) {
-        debug_assert!(round < 6);
-        self.wins[round] += 1;
+    /// Records a game won in round `round` (counting from 0) out of `turn_limit`.
+    pub fn record_win(&mut self, turn_limit: usize, round: usize) {
+        debug_assert!(round < turn_limit);
+        self.histogram(turn_limit).wins[round] += 1;
+        self.current_streak += 1;
+        self.max_streak = self.max_streak.max(self.current_streak);
+    }
+
+    pub fn record_loss(&mut self, turn_limit: usize) {
+        self.histogram(turn_limit).losses += 1;
+        self.current_streak = 0;
     }
 
-    pub fn record_loss(&mut self) {
-        self.losses += 1;
+    /// Records that hints were used in a game, in addition to its result.
+    pub fn record_hinted(&mut self) {
+        self.hinted += 1;
     }
 
-    pub fn serialize(&self, mut w: impl Write) -> Result<(), Box<dyn Error>> {
-        for win in self.wins {
-            write!(w, "{} ", win)?;
+    /// Records the result of a game from the history.
+    pub fn record(&mut self, entry: &Entry) {
+        if entry.is_won() {
+            self.record_win(entry.turn_limit, entry.guesses.len() - 1);
+        } else {
+            self.record_loss(entry.turn_limit);
         }
-        writeln!(w, "{}\n", self.losses)?;
+        if entry.hints > 0 {
+            self.record_hinted();
+        }
+    }
 
-        Ok(())
+    fn histogram(&mut self, turn_limit: usize) -> &mut Histogram {
+        self.histograms
+            .entry(turn_limit)
+            .or_insert_with(|| Histogram::new(turn_limit))
+    }
+
+    pub fn games_played(&self) -> u64 {
+        self.games_won()
+            + self
+                .histograms
+                .values()
+                .map(|h| h.losses as u64)
+                .sum::<u64>()
+    }
+
+    fn games_won(&self) -> u64 {
+        self.histograms.values().map(Histogram::games_won).sum()
+    }
+
+    /// Returns the share of games won, between 0 and 1, or `None` if no games were played.
+    pub fn win_rate(&self) -> Option<f64> {
+        let played = self.games_played();
+        (played > 0).then(|| self.games_won() as f64 / played as f64)
+    }
+
+    /// Returns the average number of guesses it took to win, or `None` if no games were won.
+    pub fn mean_guesses(&self) -> Option<f64> {
+        let won = self.games_won();
+        let guesses: u64 = self
+            .histograms
+            .values()
+            .flat_map(|h| (1..).zip(&h.wins))
+            .map(|(guesses, &n)| guesses * n as u64)
+            .sum();
+        (won > 0).then(|| guesses as f64 / won as f64)
+    }
+
+    pub fn current_streak(&self) -> u32 {
+        self.current_streak
     }
 
-    pub fn deserialize(mut r: impl Read) -> Result<Self, Box<dyn Error>> {
-        const MAX_DIGITS: usize = 10;
-        let mut buf = [0u8; MAX_DIGITS * 7 + 7];
-        let mut cursor = 0;
-        let mut n = 0;
-        while !buf[cursor - n..cursor].contains(&b'\n') {
-            n = r.read(&mut buf[cursor..])?;
-            assert!(n > 0);
-            cursor += n;
+    pub fn max_streak(&self) -> u32 {
+        self.max_streak
+    }
+
+    /// Returns the number of games in which hints were used.
+    pub fn games_hinted(&self) -> u32 {
+        self.hinted
+    }
+
+    /// Reads the stats stored at `path`, see [`file::load_or_default`].
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        file::load_or_default(path, Self::deserialize)
+    }
+
+    /// Reads the stats file, which was last written in version 4: a version line, the current and
+    /// longest streak and the number of hinted games, then one line per turn limit with the limit,
+    /// the wins of each round and the losses.
+    ///
+    /// Streaks weren't recorded before version 2, so they start out at 0 then. Games recorded
+    /// before version 3 were all played with 6 turns, and hints were only counted from version 4.
+    pub fn deserialize(r: impl Read) -> Result<Self, Box<dyn Error>> {
+        let buf = file::read_capped(r)?;
+
+        let mut lines = buf.split_inclusive('\n');
+        let mut next_line = || match lines.next() {
+            Some(line) if line.ends_with('\n') => Ok(line.trim_end()),
+            _ => Err("unexpected end of stats"),
+        };
+
+        let first = next_line()?;
+        let mut stats = Stats::default();
+        match first.strip_prefix('v') {
+            // the first version had no version line
+            None => {
+                let [wins @ .., losses] = parse_array::<7>(first)?;
+                stats
+                    .histograms
+                    .insert(LEGACY_TURN_LIMIT, Histogram::legacy(wins, losses));
+            }
+            Some("2") => {
+                let [wins @ .., losses, current_streak, max_streak] =
+                    parse_array::<9>(next_line()?)?;
+                stats
+                    .histograms
+                    .insert(LEGACY_TURN_LIMIT, Histogram::legacy(wins, losses));
+                stats.current_streak = current_streak;
+                stats.max_streak = max_streak;
+            }
+            Some(version @ ("3" | "4")) => {
+                if version == "3" {
+                    [stats.current_streak, stats.max_streak] = parse_array(next_line()?)?;
+                } else {
+                    [stats.current_streak, stats.max_streak, stats.hinted] =
+                        parse_array(next_line()?)?;
+                }
+                for line in lines {
+                    // a partial line is a histogram cut short
+                    if !line.ends_with('\n') {
+                        return Err("unexpected end of stats".into());
+                    }
+                    let numbers = parse_numbers(line)?;
+                    let Some((&turn_limit, rest)) = numbers.split_first() else {
+                        continue;
+                    };
+                    let turn_limit = turn_limit as usize;
+                    let Some((&losses, wins)) = rest.split_last() else {
+                        return Err(format!("expected {} numbers", turn_limit + 2).into());
+                    };
+                    if wins.len() != turn_limit {
+                        return Err(format!("expected {} numbers", turn_limit + 2).into());
+                    }
+                    let histogram = Histogram {
+                        wins: wins.to_vec(),
+                        losses,
+                    };
+                    if stats.histograms.insert(turn_limit, histogram).is_some() {
+                        return Err(format!("duplicate turn limit: {turn_limit}").into());
+                    }
+                }
+            }
+            Some(version) => return Err(format!("unsupported stats version: {version}").into()),
         }
-        let buf = &buf[..cursor];
-
-        let mut wins = [0u32; 6];
-        let mut start = 0;
-        for win in wins.iter_mut() {
-            let Some(end) = buf[start..].iter().position(|b| *b == b' ') else {todo!()};
-            let end = start + end;
-            let digits = std::str::from_utf8(&buf[start..end])?;
-            *win = digits.parse()?;
-            start = end + 1;
+        Ok(stats)
+    }
+}
+
+/// Parses a line of space-separated numbers.
+fn parse_numbers(line: &str) -> Result<Vec<u32>, Box<dyn Error>> {
+    Ok(line
+        .split_ascii_whitespace()
+        .map(str::parse)
+        .collect::<Result<_, _>>()?)
+}
+
+/// Parses a line of exactly `M` space-separated numbers.
+fn parse_array<const M: usize>(line: &str) -> Result<[u32; M], Box<dyn Error>> {
+    parse_numbers(line)?
+        .try_into()
+        .map_err(|_| format!("expected {M} numbers").into())
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Played: {}", self.games_played())?;
+        if let Some(rate) = self.win_rate() {
+            write!(f, "  Win %: {:.0}", rate * 100.0)?;
+        }
+        write!(
+            f,
+            "  Streak: {}  Max streak: {}",
+            self.current_streak(),
+            self.max_streak()
+        )?;
+        if let Some(mean) = self.mean_guesses() {
+            write!(f, "  Mean guesses: {mean:.2}")?;
+        }
+        if self.hinted > 0 {
+            write!(f, "  Hinted: {}", self.games_hinted())?;
         }
+        writeln!(f, "\n")?;
 
-        let Some(end) = buf[start..].iter().position(|b| *b == b'\n') else {todo!()};
-        let digits = std::str::from_utf8(&buf[start..start + end])?;
-        let losses = digits.parse()?;
+        // only label the histograms if there is more than one
+        let labeled = self.histograms.len() > 1;
+        for (i, (turn_limit, histogram)) in self.histograms.iter().enumerate() {
+            if i > 0 {
+                writeln!(f, "\n")?;
+            }
+            if labeled {
+                writeln!(f, "{turn_limit} turns:")?;
+            }
+            write!(f, "{histogram}")?;
+        }
 
-        Ok(Stats { wins, losses })
+        Ok(())
     }
 }
 
-impl fmt::Display for Stats {
+impl fmt::Display for Histogram {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         const SCALE: f32 = 30.0;
-        let max = self.wins.iter().copied().max().unwrap().max(self.losses) as f32;
+        let max = self
+            .wins
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(self.losses) as f32;
 
-        for win in self.wins {
+        for &win in &self.wins {
             let proportion = win as f32 / max;
             let columns = proportion * SCALE;
             write!(f, "{win} | ")?;
@@ -91,34 +318,98 @@ impl fmt::Display for Stats {
 mod tests {
     use super::*;
 
+    fn legacy(wins: [u32; 6], losses: u32) -> Stats {
+        Stats {
+            histograms: [(6, Histogram::legacy(wins, losses))].into(),
+            ..Stats::default()
+        }
+    }
+
     #[test]
     fn test_deserialize() {
         let des = Stats::deserialize(&b"0 0 0 0 0 0 0\n"[..]).unwrap();
-        assert_eq!(
-            des,
-            Stats {
-                wins: [0; 6],
-                losses: 0,
-            }
-        );
+        assert_eq!(des, legacy([0; 6], 0));
 
         let des = Stats::deserialize(&b"1 2 3 4 5 6 7\n"[..]).unwrap();
-        assert_eq!(
-            des,
-            Stats {
-                wins: [1, 2, 3, 4, 5, 6],
-                losses: 7,
-            }
-        );
+        assert_eq!(des, legacy([1, 2, 3, 4, 5, 6], 7));
 
         let des = Stats::deserialize(format!("{0} {0} {0} {0} {0} {0} {0}\n", u32::MAX).as_bytes())
             .unwrap();
+        assert_eq!(des, legacy([u32::MAX; 6], u32::MAX));
+
+        let des = Stats::deserialize(&b"v2\n1 2 3 4 5 6 7 8 9\n"[..]).unwrap();
         assert_eq!(
             des,
             Stats {
-                wins: [u32::MAX; 6],
-                losses: u32::MAX,
+                current_streak: 8,
+                max_streak: 9,
+                ..legacy([1, 2, 3, 4, 5, 6], 7)
             }
         );
     }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        for invalid in [
+            "",
+            "1 2 3",
+            "1 2 3 4 5 6 7",
+            "1 2 3 4 5 6\n",
+            "1 2 x 4 5 6 7\n",
+            "v2\n1 2 3 4 5 6 7\n",
+            "v3\n1 2 3\n",
+            "v3\n1 2\n3 1 2 3\n",
+            "v3\n1 2\n2 1 2 3\n2 1 2 3\n",
+            "v4\n1 2\n",
+            "v4\n1 2 3\n2 1 2 3",
+            "v5\n1 2 3\n",
+        ] {
+            assert!(
+                Stats::deserialize(invalid.as_bytes()).is_err(),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaks() {
+        let mut stats = Stats::default();
+        assert_eq!(stats.win_rate(), None);
+        assert_eq!(stats.mean_guesses(), None);
+
+        stats.record_win(6, 2);
+        stats.record_win(6, 3);
+        stats.record_loss(6);
+        stats.record_win(8, 0);
+        stats.record_hinted();
+        assert_eq!(stats.games_played(), 4);
+        assert_eq!(stats.win_rate(), Some(0.75));
+        assert_eq!(stats.mean_guesses(), Some(8.0 / 3.0));
+        assert_eq!((stats.current_streak(), stats.max_streak()), (1, 2));
+
+        let v4 = "v4\n1 2 1\n6 0 0 1 1 0 0 1\n8 1 0 0 0 0 0 0 0 0\n";
+        assert_eq!(Stats::deserialize(v4.as_bytes()).unwrap(), stats);
+
+        // version 3 had no hints
+        let v3 = v4.replace("v4\n1 2 1\n", "v3\n1 2\n");
+        let des = Stats::deserialize(v3.as_bytes()).unwrap();
+        assert_eq!(des.games_hinted(), 0);
+        assert_eq!(des.histograms, stats.histograms);
+    }
+
+    #[test]
+    fn test_record() {
+        let entry = |guesses: &str, hints| {
+            format!("2024-05-01 93 6 0 {hints} FOCAL {guesses} seed 42")
+                .parse::<Entry>()
+                .unwrap()
+        };
+        let mut stats = legacy([1, 0, 0, 0, 0, 0], 0);
+        stats.record(&entry("CRANE,FOCAL", 1));
+        stats.record(&entry("CRANE,CRANE,CRANE,CRANE,CRANE,CRANE", 0));
+        let mut expected = legacy([1, 1, 0, 0, 0, 0], 1);
+        expected.max_streak = 1;
+        expected.hinted = 1;
+        assert_eq!(stats, expected);
+    }
 }
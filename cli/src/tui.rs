@@ -0,0 +1,305 @@
+//! A full-screen terminal UI.
+//!
+//! The terminal is switched into raw mode with `stty`, so key presses arrive one at a time, and
+//! the whole screen is redrawn with ANSI escapes after each of them.
+use std::fmt::{self, Write as _};
+use std::io::{self, stdin, stdout, BufRead, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use engine::{Game, Letters};
+use words::Word;
+
+use crate::hint::{self, Solvers};
+use crate::palette::Palette;
+use crate::save::SavedGame;
+use crate::{next_hint, play_guess, BOARDS_PER_ROW, KEYBOARD};
+
+/// The delay between revealing two letters of a guess.
+const REVEAL_DELAY: Duration = Duration::from_millis(150);
+
+/// Plays the game of `saved` until it is over, drawing the letters with `palette` and saving the
+/// game after each guess. Returns `false` if the player quit before that.
+pub fn play<const N: usize>(saved: &mut SavedGame<N>, palette: Palette) -> io::Result<bool> {
+    let _raw_mode = RawMode::enable()?;
+    let mut screen = Screen::new()?;
+    let mut stdin = stdin().lock();
+    let mut typed = Vec::with_capacity(N);
+    let mut message = String::new();
+    let mut solvers = Solvers::new(hint::matrix_path());
+
+    while !saved.game.is_over() {
+        let game = &mut saved.game;
+        screen.draw(game, palette, &typed, None, &game.letters(), &message)?;
+        match read_key(&mut stdin)? {
+            Key::Letter(c) if typed.len() < N => typed.push(c.to_ascii_uppercase()),
+            Key::Backspace => {
+                typed.pop();
+            }
+            Key::Enter => {
+                let Ok(guess) = Word::<N>::try_from(&typed[..]) else {
+                    message = "Not enough letters".to_string();
+                    continue;
+                };
+                // don't give away the letters still being revealed on the keyboard
+                let letters = game.letters();
+                if let Err(e) = play_guess(game, &guess) {
+                    message = e.to_string();
+                    continue;
+                }
+                typed.clear();
+                for revealed in 0..N {
+                    screen.draw(game, palette, &typed, Some(revealed), &letters, "")?;
+                    thread::sleep(REVEAL_DELAY);
+                }
+                if let Err(e) = saved.autosave() {
+                    message = format!("Failed to save the game: {e}");
+                    continue;
+                }
+            }
+            Key::Hint => {
+                message = next_hint(game, &mut solvers).to_string();
+                if let Err(e) = saved.autosave() {
+                    message = format!("Failed to save the game: {e}");
+                }
+                continue;
+            }
+            Key::Quit => return Ok(false),
+            _ => {}
+        }
+        message.clear();
+    }
+
+    let game = &saved.game;
+    screen.draw(
+        game,
+        palette,
+        &typed,
+        None,
+        &game.letters(),
+        "Press any key",
+    )?;
+    read_key(&mut stdin)?;
+    Ok(true)
+}
+
+/// Puts the terminal into raw mode, restoring the previous settings when dropped.
+struct RawMode {
+    /// The settings to restore, as printed by `stty -g`.
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+        Ok(Self {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        // nothing sensible to do if this fails, the shell's `reset` will have to fix things
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+/// Runs `stty` on the terminal connected to stdin and returns its output.
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("stty failed, is stdin a terminal?"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Switches to the terminal's alternate screen, switching back when dropped.
+struct Screen {
+    out: io::StdoutLock<'static>,
+}
+
+impl Screen {
+    fn new() -> io::Result<Self> {
+        let mut out = stdout().lock();
+        // alternate screen, hide the cursor
+        write!(out, "\x1b[?1049h\x1b[?25l")?;
+        Ok(Self { out })
+    }
+
+    /// Redraws the whole screen, see [`render_frame`].
+    fn draw<const N: usize>(
+        &mut self,
+        game: &Game<N>,
+        palette: Palette,
+        typed: &[u8],
+        revealed: Option<usize>,
+        letters: &Letters,
+        message: &str,
+    ) -> io::Result<()> {
+        // the frame is written at once to avoid flickering
+        let mut frame = String::new();
+        render_frame(&mut frame, game, palette, typed, revealed, letters, message).expect("OOM");
+        self.out.write_all(frame.as_bytes())?;
+        self.out.flush()
+    }
+}
+
+/// Renders the grids of the boards side by side, a message below them and the keyboard drawn by
+/// `letters` to `f`, drawing the letters with `palette`.
+///
+/// If `revealed` is set, only that many letters of the last guess are scored.
+fn render_frame<const N: usize>(
+    f: &mut String,
+    game: &Game<N>,
+    palette: Palette,
+    typed: &[u8],
+    revealed: Option<usize>,
+    letters: &Letters,
+    message: &str,
+) -> fmt::Result {
+    // in raw mode, "\n" only moves down, so every line ends in "\r\n"
+    write!(f, "\x1b[H\x1b[2J\r\n")?;
+
+    for boards in game.boards().chunks(BOARDS_PER_ROW) {
+        for row in 0..game.turn_limit() {
+            write!(f, "  ")?;
+            for board in boards {
+                let guesses = board.guesses();
+                let (word, score) = match guesses.get(row) {
+                    Some((word, score)) => (&word[..], Some(*score)),
+                    None if row == guesses.len() && !board.is_won() => (typed, None),
+                    None => (&[][..], None),
+                };
+                // boards solved earlier didn't get the last guess
+                let last = row + 1 == game.turn() && guesses.len() == game.turn();
+                let revealed = revealed.filter(|_| last).unwrap_or(N);
+                for i in 0..N {
+                    match (word.get(i), score) {
+                        (Some(&c), Some(score)) if i < revealed => {
+                            palette.write_letter(&mut *f, c as char, Some(score.get(i)), true)?
+                        }
+                        // typed, or not revealed yet
+                        (Some(&c), _) => write!(f, "\x1b[1;4m {} \x1b[m", c as char)?,
+                        (None, _) => write!(f, "\x1b[2m _ \x1b[m")?,
+                    }
+                    write!(f, " ")?;
+                }
+                write!(f, "  ")?;
+            }
+            write!(f, "\r\n")?;
+        }
+        write!(f, "\r\n")?;
+    }
+
+    write!(f, "  {message}\r\n\r\n")?;
+
+    for (indent, row) in KEYBOARD.iter().enumerate() {
+        write!(f, "{:width$}", "", width = 2 + indent * 2)?;
+        for c in row.bytes() {
+            let letter = letters[(c - b'A') as usize];
+            palette.write_letter(&mut *f, c as char, letter, true)?;
+            write!(f, " ")?;
+        }
+        write!(f, "\r\n")?;
+    }
+    write!(
+        f,
+        "\r\n  \x1b[2mtype a guess, enter to submit, ? for a hint, ctrl-c to quit\x1b[m\r\n"
+    )
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = write!(self.out, "\x1b[?25h\x1b[?1049l");
+        let _ = self.out.flush();
+    }
+}
+
+/// A key press read in raw mode.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Key {
+    Letter(u8),
+    Backspace,
+    Enter,
+    /// `?`, asking for a hint.
+    Hint,
+    /// Ctrl-C or Ctrl-D, or the end of the input.
+    Quit,
+    Other,
+}
+
+/// Reads a key press from `r`.
+///
+/// Escape sequences such as arrow keys are skipped, so their final letter isn't typed. The
+/// terminal sends a sequence all at once, so only the bytes already buffered are looked at: a lone
+/// Esc must not wait for the next key.
+fn read_key(r: &mut impl BufRead) -> io::Result<Key> {
+    let buf = r.fill_buf()?;
+    let Some(&byte) = buf.first() else {
+        return Ok(Key::Quit);
+    };
+    let mut len = 1;
+    let key = match byte {
+        c if c.is_ascii_alphabetic() => Key::Letter(c),
+        0x7f | 0x08 => Key::Backspace,
+        b'\r' | b'\n' => Key::Enter,
+        b'?' => Key::Hint,
+        0x03 | 0x04 => Key::Quit,
+        0x1b if matches!(buf.get(1), Some(b'[' | b'O')) => {
+            len = match buf[2..].iter().position(|c| (0x40..=0x7e).contains(c)) {
+                Some(end) => end + 3,
+                None => buf.len(),
+            };
+            Key::Other
+        }
+        _ => Key::Other,
+    };
+    r.consume(len);
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    #[test]
+    fn test_read_key() {
+        let mut input = &b"cR\x7f\r\x1b[A\x1b[1;5C?x\x03"[..];
+        let mut keys = Vec::new();
+        while !input.is_empty() {
+            keys.push(read_key(&mut input).unwrap());
+        }
+        assert_eq!(
+            keys,
+            [
+                Key::Letter(b'c'),
+                Key::Letter(b'R'),
+                Key::Backspace,
+                Key::Enter,
+                Key::Other,
+                Key::Other,
+                Key::Hint,
+                Key::Letter(b'x'),
+                Key::Quit
+            ]
+        );
+        assert_eq!(read_key(&mut input).unwrap(), Key::Quit);
+
+        // a lone Esc, with the next key pressed later
+        let mut input = (&b"\x1b"[..]).chain(&b"c"[..]);
+        assert_eq!(read_key(&mut input).unwrap(), Key::Other);
+        assert_eq!(read_key(&mut input).unwrap(), Key::Letter(b'c'));
+        let mut input = &b"\x1bc"[..];
+        assert_eq!(read_key(&mut input).unwrap(), Key::Other);
+        assert_eq!(read_key(&mut input).unwrap(), Key::Letter(b'c'));
+    }
+}
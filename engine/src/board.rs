@@ -0,0 +1,120 @@
+use words::adversary::Adversary;
+use words::score::{score, LetterScore, Score};
+use words::Word;
+
+/// The best known score of each letter from A to Z, `None` for letters not guessed yet.
+pub type Letters = [Option<LetterScore>; 26];
+
+/// The guesses scored against one answer of a [`Game`](crate::Game).
+#[derive(Clone, Debug)]
+pub struct Board<const N: usize = 5> {
+    word: Word<N>,
+    /// The guesses up to the one that solved the board.
+    guesses: Vec<(Word<N>, Score<N>)>,
+    letters: Letters,
+    /// Scores the guesses in adversarial games, in which `word` is just one of the words that
+    /// are still possible.
+    adversary: Option<Adversary<N>>,
+}
+
+impl<const N: usize> Board<N> {
+    pub(crate) fn new(word: Word<N>) -> Self {
+        Self {
+            word,
+            guesses: Vec::new(),
+            letters: [None; 26],
+            adversary: None,
+        }
+    }
+
+    /// Creates a board whose word is only decided by `adversary` as the game goes on.
+    pub(crate) fn adversarial(adversary: Adversary<N>) -> Self {
+        let mut board = Self::new(adversary.candidates()[0]);
+        board.adversary = Some(adversary);
+        board
+    }
+
+    pub(crate) fn score(&mut self, guess: &Word<N>) -> Score<N> {
+        let score = match &mut self.adversary {
+            Some(adversary) => {
+                let score = adversary.guess(guess);
+                self.word = adversary.candidates()[0];
+                score
+            }
+            None => score(&self.word, guess),
+        };
+        self.guesses.push((*guess, score));
+        for (&c, letter) in guess.iter().zip(score) {
+            if !c.is_ascii_uppercase() {
+                continue;
+            }
+            let best = &mut self.letters[(c - b'A') as usize];
+            // Right beats InWord beats Wrong
+            if best.is_none_or(|best| (best as u8) < letter as u8) {
+                *best = Some(letter);
+            }
+        }
+        score
+    }
+
+    /// Returns the answer. In adversarial games, this is one of the words that are still
+    /// possible, which may change with every guess.
+    pub fn word(&self) -> &Word<N> {
+        &self.word
+    }
+
+    pub fn word_as_str(&self) -> &str {
+        words::to_str(&self.word)
+    }
+
+    /// Returns whether the answer is picked by an adversary, see [`words::adversary`].
+    pub fn is_adversarial(&self) -> bool {
+        self.adversary.is_some()
+    }
+
+    /// Returns the best score each letter got so far.
+    pub fn letters(&self) -> &Letters {
+        &self.letters
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.guesses.last().is_some_and(|(_, score)| score.is_win())
+    }
+
+    /// Returns the guesses made so far and their scores.
+    pub fn guesses(&self) -> &[(Word<N>, Score<N>)] {
+        &self.guesses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters() {
+        let mut board = Board::new(*b"CLOSE");
+        for guess in [b"CRANE", b"CLOSE"] {
+            board.score(guess);
+        }
+        let letter = |c: u8| board.letters()[(c - b'A') as usize];
+        assert_eq!(letter(b'C'), Some(LetterScore::Right));
+        assert_eq!(letter(b'R'), Some(LetterScore::Wrong));
+        // yellow in CRANE, green in CLOSE
+        assert_eq!(letter(b'E'), Some(LetterScore::Right));
+        assert_eq!(letter(b'Z'), None);
+    }
+
+    #[test]
+    fn test_adversarial() {
+        let adversary = Adversary::new(vec![*b"CIGAR", *b"SISSY", *b"HUMPH", *b"CLOSE"]);
+        let mut board = Board::adversarial(adversary);
+        for guess in [b"CRANE", b"SISSY"] {
+            assert_eq!(board.score(guess), Score::default());
+            assert!(!board.is_won());
+        }
+        assert_eq!(board.word_as_str(), "HUMPH");
+        board.score(b"HUMPH");
+        assert!(board.is_won());
+    }
+}
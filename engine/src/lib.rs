@@ -0,0 +1,481 @@
+//! The rules of a game of Wordle, shared by every frontend.
+//!
+//! A [`Game`] holds one or more [boards](Board), each with its own answer, which are all guessed at
+//! once. It checks that guesses are allowed, scores them, keeps track of the turns and decides
+//! when the game is won or lost. Frontends only need to read guesses and draw the result.
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use words::adversary::Adversary;
+use words::file;
+use words::hard::{self, Violation};
+use words::score::{LetterScore, Score};
+use words::{Word, WordList, MAX_TURN_LIMIT};
+
+pub use board::{Board, Letters};
+
+mod board;
+
+/// The version of the format written by [`Game::serialize`].
+const VERSION: u32 = 1;
+
+/// A game in progress or finished, see the [crate docs](crate).
+#[derive(Clone, Debug)]
+pub struct Game<const N: usize = 5> {
+    boards: Vec<Board<N>>,
+    /// The words that may be guessed, besides the built-in ones.
+    word_list: WordList<N>,
+    turn_limit: usize,
+    hard: bool,
+    hints: usize,
+    history: Vec<GuessOutcome<N>>,
+}
+
+/// Whether a game is still going on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Playing,
+    /// Every board was solved.
+    Won,
+    /// The turns ran out before every board was solved.
+    Lost,
+}
+
+/// A guess and how it was scored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GuessOutcome<const N: usize = 5> {
+    pub guess: Word<N>,
+    /// The score on each board, in order. `None` for boards that were solved before.
+    pub scores: Vec<Option<Score<N>>>,
+    /// The status of the game after the guess.
+    pub status: Status,
+}
+
+impl<const N: usize> Game<N> {
+    /// Creates a game with one board for each of `answers`, of which there must be at least one.
+    /// Guesses must be in `word_list` or, for 5-letter words, one of the built-in words. The turn
+    /// limit must be in `1..=`[`MAX_TURN_LIMIT`].
+    pub fn new(word_list: WordList<N>, answers: &[Word<N>], turn_limit: usize) -> Self {
+        assert!(!answers.is_empty(), "a game needs at least one answer");
+        Self::with_boards(
+            word_list,
+            answers.iter().copied().map(Board::new).collect(),
+            turn_limit,
+        )
+    }
+
+    /// Creates a game with a single board whose answer is picked from `word_list` by an
+    /// [`Adversary`]. The turn limit must be in `1..=`[`MAX_TURN_LIMIT`].
+    pub fn adversarial(word_list: WordList<N>, turn_limit: usize) -> Self {
+        let adversary = Adversary::new(word_list.words().to_vec());
+        Self::with_boards(word_list, vec![Board::adversarial(adversary)], turn_limit)
+    }
+
+    fn with_boards(word_list: WordList<N>, boards: Vec<Board<N>>, turn_limit: usize) -> Self {
+        assert!(
+            (1..=MAX_TURN_LIMIT).contains(&turn_limit),
+            "invalid turn limit: {turn_limit}"
+        );
+        Self {
+            boards,
+            word_list,
+            turn_limit,
+            hard: false,
+            hints: 0,
+            history: Vec::with_capacity(turn_limit),
+        }
+    }
+
+    /// Enables or disables hard mode, see [`words::hard`].
+    pub fn with_hard_mode(mut self, hard: bool) -> Self {
+        self.hard = hard;
+        self
+    }
+
+    /// Plays `guess`, which must be in uppercase, scoring it against every board that isn't
+    /// solved yet.
+    pub fn guess(&mut self, guess: &Word<N>) -> Result<GuessOutcome<N>, GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        if !self.is_word(guess) {
+            return Err(GuessError::NotAWord);
+        }
+        if self.hard {
+            for board in self.unsolved() {
+                hard::validate(guess, board.guesses()).map_err(GuessError::HardMode)?;
+            }
+        }
+
+        let scores = self
+            .boards
+            .iter_mut()
+            .map(|board| (!board.is_won()).then(|| board.score(guess)))
+            .collect();
+        let outcome = GuessOutcome {
+            guess: *guess,
+            scores,
+            status: Status::Playing,
+        };
+        self.history.push(outcome);
+        let status = self.status();
+        let outcome = self.history.last_mut().unwrap();
+        outcome.status = status;
+        Ok(outcome.clone())
+    }
+
+    /// Returns whether `guess` is in the word list or, for 5-letter words, one of the built-in
+    /// words.
+    pub fn is_word(&self, guess: &Word<N>) -> bool {
+        self.word_list.contains(guess) || <&Word>::try_from(&guess[..]).is_ok_and(words::check)
+    }
+
+    /// Records that the player asked for a hint. Hints don't use up a turn.
+    pub fn record_hint(&mut self) {
+        self.hints += 1;
+    }
+
+    /// Returns the number of hints the player asked for.
+    pub fn hints(&self) -> usize {
+        self.hints
+    }
+
+    pub fn boards(&self) -> &[Board<N>] {
+        &self.boards
+    }
+
+    /// Returns the boards that aren't solved yet.
+    pub fn unsolved(&self) -> impl Iterator<Item = &Board<N>> {
+        self.boards.iter().filter(|board| !board.is_won())
+    }
+
+    /// Returns the list the answers were picked from.
+    pub fn word_list(&self) -> &WordList<N> {
+        &self.word_list
+    }
+
+    /// Returns the guesses made so far, in order.
+    pub fn history(&self) -> &[GuessOutcome<N>] {
+        &self.history
+    }
+
+    /// Returns the best score each letter got on the boards that aren't solved yet, or on all
+    /// boards once they are.
+    pub fn letters(&self) -> Letters {
+        let mut letters = [None; 26];
+        let unsolved: Vec<_> = self.unsolved().collect();
+        let boards = if unsolved.is_empty() {
+            self.boards.iter().collect()
+        } else {
+            unsolved
+        };
+        for board in boards {
+            for (best, &letter) in letters.iter_mut().zip(board.letters()) {
+                *best = (*best).max(letter.map(|letter| letter as u8));
+            }
+        }
+        letters.map(|best| best.map(|letter| LetterScore::ALL[letter as usize]))
+    }
+
+    /// Returns the words of all boards, e.g. `CIGAR` or `CIGAR, REBUT`.
+    pub fn words_as_str(&self) -> String {
+        let words: Vec<_> = self.boards.iter().map(Board::word_as_str).collect();
+        words.join(", ")
+    }
+
+    /// Returns the number of guesses made so far.
+    pub fn turn(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns the number of guesses the player is allowed to make.
+    pub fn turn_limit(&self) -> usize {
+        self.turn_limit
+    }
+
+    pub fn is_hard(&self) -> bool {
+        self.hard
+    }
+
+    pub fn status(&self) -> Status {
+        if self.boards.iter().all(Board::is_won) {
+            Status::Won
+        } else if self.turn() == self.turn_limit {
+            Status::Lost
+        } else {
+            Status::Playing
+        }
+    }
+
+    /// Returns whether every board is solved.
+    pub fn is_won(&self) -> bool {
+        self.status() == Status::Won
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.status() != Status::Playing
+    }
+
+    /// Writes this game in a line-based text format that [`Game::deserialize`] reads back, e.g.
+    ///
+    /// ```text
+    /// v1
+    /// turns 6
+    /// hard 0
+    /// hints 1
+    /// words CIGAR
+    /// guesses CRANE FOCAL
+    /// ```
+    ///
+    /// Adversarial games have `words adversarial`, since their answers follow from the guesses.
+    pub fn serialize(&self, mut w: impl Write) -> io::Result<()> {
+        writeln!(w, "v{VERSION}")?;
+        writeln!(w, "turns {}", self.turn_limit)?;
+        writeln!(w, "hard {}", self.hard as u8)?;
+        writeln!(w, "hints {}", self.hints)?;
+        if self.boards.iter().any(Board::is_adversarial) {
+            writeln!(w, "words adversarial")?;
+        } else {
+            writeln!(w, "words {}", self.words_as_str().replace(',', ""))?;
+        }
+        write!(w, "guesses")?;
+        for outcome in &self.history {
+            write!(w, " {}", words::to_str(&outcome.guess))?;
+        }
+        writeln!(w)
+    }
+
+    /// Reads a game written by [`Game::serialize`], whose guesses are checked against
+    /// `word_list` again.
+    pub fn deserialize(r: impl Read, word_list: WordList<N>) -> Result<Self, LoadError> {
+        let buf = file::read_capped(r)?;
+
+        let mut lines = buf.lines();
+        let version = lines.next().and_then(|line| line.strip_prefix('v'));
+        if version.and_then(|version| version.parse().ok()) != Some(VERSION) {
+            return Err(LoadError::Format);
+        }
+        let mut field = |name: &str| {
+            let line = lines.next().ok_or(LoadError::Format)?;
+            match line.strip_prefix(name).ok_or(LoadError::Format)? {
+                "" => Ok(""),
+                rest => rest.strip_prefix(' ').ok_or(LoadError::Format),
+            }
+        };
+        let turn_limit = field("turns")?.parse().map_err(|_| LoadError::Format)?;
+        if !(1..=MAX_TURN_LIMIT).contains(&turn_limit) {
+            return Err(LoadError::Format);
+        }
+        let hard = match field("hard")? {
+            "0" => false,
+            "1" => true,
+            _ => return Err(LoadError::Format),
+        };
+        let hints = field("hints")?.parse().map_err(|_| LoadError::Format)?;
+        let mut game = match field("words")? {
+            "adversarial" => Self::adversarial(word_list, turn_limit),
+            answers => {
+                let answers = parse_words(answers)?;
+                if answers.is_empty() {
+                    return Err(LoadError::Format);
+                }
+                Self::new(word_list, &answers, turn_limit)
+            }
+        };
+        game.hard = hard;
+        game.hints = hints;
+        for guess in parse_words(field("guesses")?)? {
+            game.guess(&guess).map_err(LoadError::Guess)?;
+        }
+        Ok(game)
+    }
+}
+
+/// Parses space-separated words of uppercase letters.
+fn parse_words<const N: usize>(s: &str) -> Result<Vec<Word<N>>, LoadError> {
+    s.split_ascii_whitespace()
+        .map(|word| {
+            let word = Word::<N>::try_from(word.as_bytes()).map_err(|_| LoadError::Format)?;
+            if !word.iter().all(u8::is_ascii_uppercase) {
+                return Err(LoadError::Format);
+            }
+            Ok(word)
+        })
+        .collect()
+}
+
+/// Why a guess was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuessError {
+    /// The game is already won or lost.
+    GameOver,
+    /// The guess is not in the word list.
+    NotAWord,
+    /// The guess ignores a hint while playing in hard mode.
+    HardMode(Violation),
+}
+
+impl Error for GuessError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::HardMode(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GameOver => write!(f, "the game is over"),
+            Self::NotAWord => write!(f, "not a word"),
+            Self::HardMode(v) => write!(f, "hard mode: {v}"),
+        }
+    }
+}
+
+/// The error returned when reading a [`Game`] fails.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    /// The data is not a game written by [`Game::serialize`].
+    Format,
+    /// A guess of the game is not allowed, e.g. because the word list changed since.
+    Guess(GuessError),
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Guess(e) => Some(e),
+            Self::Format => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read game: {e}"),
+            Self::Format => write!(f, "not a saved game"),
+            Self::Guess(e) => write!(f, "invalid guess in saved game: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_list() -> WordList {
+        WordList::from_reader(&b"CIGAR\nREBUT\nSISSY\nHUMPH\nCLOSE"[..]).unwrap()
+    }
+
+    #[test]
+    fn test_guess() {
+        let mut game = Game::new(word_list(), &[*b"CLOSE", *b"CIGAR"], 3);
+        assert_eq!(game.guess(b"CLOSX"), Err(GuessError::NotAWord));
+
+        let outcome = game.guess(b"CLOSE").unwrap();
+        assert_eq!(
+            outcome.scores,
+            [Some(Score::PERFECT), Some("gbbbb".parse().unwrap())]
+        );
+        assert_eq!(outcome.status, Status::Playing);
+        // C is only right on the board that's left
+        assert_eq!(
+            game.letters()[(b'L' - b'A') as usize],
+            Some(LetterScore::Wrong)
+        );
+
+        // built-in words are allowed too
+        let outcome = game.guess(b"CRANE").unwrap();
+        assert_eq!(outcome.scores[0], None);
+        let outcome = game.guess(b"CIGAR").unwrap();
+        assert_eq!(outcome.status, Status::Won);
+        assert!(game.is_over());
+        assert_eq!(game.history().len(), 3);
+        assert_eq!(game.boards()[0].guesses().len(), 1);
+        assert_eq!(game.words_as_str(), "CLOSE, CIGAR");
+        assert_eq!(game.guess(b"CIGAR"), Err(GuessError::GameOver));
+        // all boards count once they're solved
+        assert_eq!(
+            game.letters()[(b'L' - b'A') as usize],
+            Some(LetterScore::Right)
+        );
+    }
+
+    #[test]
+    fn test_lost_and_hard() {
+        let mut game = Game::new(word_list(), &[*b"CLOSE"], 2).with_hard_mode(true);
+        game.guess(b"CIGAR").unwrap();
+        assert_eq!(
+            game.guess(b"HUMPH"),
+            Err(GuessError::HardMode(Violation::Misplaced {
+                at: 0,
+                letter: b'C'
+            }))
+        );
+        assert_eq!(game.guess(b"CRANE").unwrap().status, Status::Lost);
+        assert!(!game.is_won());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut game = Game::new(word_list(), &[*b"CLOSE", *b"CIGAR"], 7).with_hard_mode(true);
+        game.guess(b"CIGAR").unwrap();
+        game.record_hint();
+        game.guess(b"CLOSE").unwrap();
+
+        let mut buf = Vec::new();
+        game.serialize(&mut buf).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&buf),
+            "v1\nturns 7\nhard 1\nhints 1\nwords CLOSE CIGAR\nguesses CIGAR CLOSE\n"
+        );
+        let des = Game::deserialize(&buf[..], word_list()).unwrap();
+        assert_eq!(des.history(), game.history());
+        assert_eq!((des.turn_limit(), des.is_hard(), des.hints()), (7, true, 1));
+
+        let mut game = Game::adversarial(word_list(), 6);
+        game.guess(b"CRANE").unwrap();
+        let mut buf = Vec::new();
+        game.serialize(&mut buf).unwrap();
+        let des = Game::deserialize(&buf[..], word_list()).unwrap();
+        assert_eq!(des.boards()[0].word(), game.boards()[0].word());
+
+        for invalid in [
+            "",
+            "v2\nturns 6\nhard 0\nhints 0\nwords CLOSE\nguesses\n",
+            "v1\nturns 0\nhard 0\nhints 0\nwords CLOSE\nguesses\n",
+            "v1\nturns 18446744073709551615\nhard 0\nhints 0\nwords CLOSE\nguesses\n",
+            "v1\nturns 6\nhard 2\nhints 0\nwords CLOSE\nguesses\n",
+            "v1\nturns 6\nhard 0\nhints 0\nwords\nguesses\n",
+            "v1\nturns 6\nhard 0\nhints 0\nwords close\nguesses\n",
+            "v1\nturns 6\nhard 0\nhints 0\nwords CLOSE\n",
+        ] {
+            assert!(
+                matches!(
+                    Game::deserialize(invalid.as_bytes(), word_list()),
+                    Err(LoadError::Format)
+                ),
+                "{invalid:?}"
+            );
+        }
+        assert!(matches!(
+            Game::deserialize(
+                &b"v1\nturns 1\nhard 0\nhints 0\nwords CLOSE\nguesses CRANE CLOSE\n"[..],
+                word_list()
+            ),
+            Err(LoadError::Guess(GuessError::GameOver))
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+//! An opponent that never commits to an answer, as in the game Absurdle.
+//!
+//! Instead of picking a word up front, the [`Adversary`] keeps every word that is consistent with
+//! the guesses so far. Each guess [partitions](partition) those words by the score they would give
+//! it, and the adversary answers with the score of the largest partition. The guesser only wins
+//! once a single word is left and they guess it.
+use std::collections::HashMap;
+
+use crate::score::{score, Score};
+use crate::Word;
+
+/// Groups `candidates` by the score they give `guess`, keeping their order within each group.
+pub fn partition<const N: usize>(
+    candidates: &[Word<N>],
+    guess: &Word<N>,
+) -> HashMap<Score<N>, Vec<Word<N>>> {
+    let mut partitions: HashMap<_, Vec<_>> = HashMap::new();
+    for candidate in candidates {
+        partitions
+            .entry(score(candidate, guess))
+            .or_default()
+            .push(*candidate);
+    }
+    partitions
+}
+
+/// Scores guesses so that as many words as possible remain possible, see the [module docs](self).
+#[derive(Clone, Debug)]
+pub struct Adversary<const N: usize = 5> {
+    candidates: Vec<Word<N>>,
+}
+
+impl<const N: usize> Adversary<N> {
+    /// Creates an adversary that may pick any of `candidates`. Panics if `candidates` is empty.
+    pub fn new(candidates: Vec<Word<N>>) -> Self {
+        assert!(!candidates.is_empty(), "an adversary needs candidates");
+        Self { candidates }
+    }
+
+    /// Returns the words that are still possible.
+    pub fn candidates(&self) -> &[Word<N>] {
+        &self.candidates
+    }
+
+    /// Scores `guess` with the score that keeps the most candidates, and keeps only those.
+    ///
+    /// Among partitions of the same size, the one with the lowest [`Score::to_bits`] wins, so the
+    /// outcome doesn't depend on the order of the candidates and a perfect score is only given
+    /// once a single candidate is left.
+    pub fn guess(&mut self, guess: &Word<N>) -> Score<N> {
+        let (score, candidates) = partition(&self.candidates, guess)
+            .into_iter()
+            .max_by(|(a, a_words), (b, b_words)| {
+                a_words
+                    .len()
+                    .cmp(&b_words.len())
+                    .then(b.to_bits().cmp(&a.to_bits()))
+            })
+            .expect("there is always a candidate");
+        self.candidates = candidates;
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition() {
+        let candidates = [*b"CIGAR", *b"SISSY", *b"HUMPH", *b"CLOSE"];
+        let partitions = partition(&candidates, b"CRANE");
+        assert_eq!(partitions.len(), 3);
+        assert_eq!(partitions[&Score::default()], [*b"SISSY", *b"HUMPH"]);
+        assert_eq!(partitions[&"gyybb".parse().unwrap()], [*b"CIGAR"]);
+        assert_eq!(partitions[&"gbbbg".parse().unwrap()], [*b"CLOSE"]);
+    }
+
+    #[test]
+    fn test_guess() {
+        let mut adversary = Adversary::new(vec![*b"CIGAR", *b"SISSY", *b"HUMPH", *b"CLOSE"]);
+        assert_eq!(adversary.guess(b"CRANE"), Score::default());
+        assert_eq!(adversary.candidates(), [*b"SISSY", *b"HUMPH"]);
+
+        // both are left on their own, the lower score wins over the perfect one
+        assert_eq!(adversary.guess(b"SISSY"), Score::default());
+        assert_eq!(adversary.candidates(), [*b"HUMPH"]);
+        assert_eq!(adversary.guess(b"HUMPH"), Score::PERFECT);
+    }
+}
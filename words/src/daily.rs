@@ -0,0 +1,214 @@
+//! Deterministic daily puzzles.
+//!
+//! Every calendar day has a puzzle number, counting from [`FIRST_DAY`]. The answer to a puzzle is
+//! the word at that position of a [`WordList`], wrapping around once the list is exhausted. For
+//! the built-in list this is the same order the original game used.
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+use crate::{Word, WordList};
+
+/// The day of puzzle number 0.
+pub const FIRST_DAY: Date = Date {
+    year: 2021,
+    month: 6,
+    day: 19,
+};
+
+/// Returns the answer to the puzzle of `date` from the built-in list.
+/// Returns `None` for dates before [`FIRST_DAY`].
+pub fn daily_word(date: Date) -> Option<Word> {
+    puzzle_number(date).map(|puzzle| builtin().daily(puzzle))
+}
+
+/// Returns the puzzle number of `date`, or `None` if `date` is before [`FIRST_DAY`].
+pub fn puzzle_number(date: Date) -> Option<u32> {
+    let days = date.days_since_epoch() - FIRST_DAY.days_since_epoch();
+    u32::try_from(days).ok()
+}
+
+/// Returns the day of puzzle number `puzzle`.
+pub fn puzzle_date(puzzle: u32) -> Date {
+    Date::from_days_since_epoch(FIRST_DAY.days_since_epoch() + puzzle as i64)
+}
+
+/// The answers in the order of the daily puzzles.
+pub(crate) fn daily_order() -> impl Iterator<Item = Word> {
+    include_str!("../words.txt")
+        .lines()
+        .map(|line| Word::try_from(line.as_bytes()).expect("words.txt has invalid entries"))
+}
+
+fn builtin() -> &'static WordList {
+    static BUILTIN: OnceLock<WordList> = OnceLock::new();
+    BUILTIN.get_or_init(WordList::default)
+}
+
+/// A day of the proleptic Gregorian calendar.
+///
+/// Formats and parses as `YYYY-MM-DD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Returns `None` if `month` or `day` is out of range.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the current day in UTC, so that everyone gets the same puzzle at the same time.
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("time went backwards")
+            .as_secs();
+        Self::from_days_since_epoch((secs / (24 * 60 * 60)) as i64)
+    }
+
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the number of days since 1970-01-01.
+    pub fn days_since_epoch(self) -> i64 {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// The inverse of [`days_since_epoch`](Self::days_since_epoch).
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days.rem_euclid(146097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (year_of_era + era * 400 + (month <= 2) as i64) as i32;
+        Self { year, month, day }
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(|| ParseDateError(s.to_string()));
+        let (year, month, day) = (next()?, next()?, next()?);
+        let parse_err = |_| ParseDateError(s.to_string());
+        Self::new(
+            year.parse().map_err(parse_err)?,
+            month.parse().map_err(parse_err)?,
+            day.parse().map_err(parse_err)?,
+        )
+        .ok_or_else(|| ParseDateError(s.to_string()))
+    }
+}
+
+/// The error returned when parsing a [`Date`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDateError(String);
+
+impl Error for ParseDateError {}
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a date like 2022-01-31, found: {:?}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_daily_word() {
+        assert_eq!(daily_word(FIRST_DAY), Some(*b"CIGAR"));
+        assert_eq!(daily_word(date("2021-06-20")), Some(*b"REBUT"));
+        assert_eq!(daily_word(date("2022-01-01")), Some(*b"REBUS"));
+        assert_eq!(daily_word(date("2021-06-18")), None);
+
+        assert_eq!(puzzle_number(date("2022-01-01")), Some(196));
+        assert_eq!(puzzle_date(196), date("2022-01-01"));
+
+        // the list wraps around once all words have been used
+        let len = builtin().len() as u32;
+        assert_eq!(builtin().daily(len), *b"CIGAR");
+    }
+
+    #[test]
+    fn test_days_since_epoch() {
+        assert_eq!(date("1970-01-01").days_since_epoch(), 0);
+        assert_eq!(date("1969-12-31").days_since_epoch(), -1);
+        assert_eq!(date("2000-03-01").days_since_epoch(), 11017);
+        for days in -1000..100_000 {
+            let date = Date::from_days_since_epoch(days);
+            assert_eq!(date.days_since_epoch(), days, "{date}");
+            assert_eq!(date.to_string().parse(), Ok(date));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(date("2024-02-29").to_string(), "2024-02-29");
+        assert_eq!(date("2024-2-9").to_string(), "2024-02-09");
+        for invalid in [
+            "2023-02-29",
+            "2023-13-01",
+            "2023-00-10",
+            "2023-01",
+            "tomorrow",
+            "",
+        ] {
+            assert!(invalid.parse::<Date>().is_err(), "{invalid}");
+        }
+    }
+}
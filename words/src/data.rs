@@ -1,12 +1,15 @@
+use std::collections::HashSet;
 use std::sync::OnceLock;
 
-use rustc_hash::FxHashSet;
+use crate::hash::WordHashBuilder;
 
-pub type Word = [u8; 5];
+/// A word of `N` uppercase ASCII letters.
+pub type Word<const N: usize = 5> = [u8; N];
 
 pub const WORD_COUNT: usize = 2315;
 
-pub const WORDS: [&Word; WORD_COUNT] = [
+/// The words that can be picked as an answer.
+pub static WORDS: [&Word; WORD_COUNT] = [
     b"ABACK", b"ABASE", b"ABATE", b"ABBEY", b"ABBOT", b"ABHOR", b"ABIDE", b"ABLED", b"ABODE",
     b"ABORT", b"ABOUT", b"ABOVE", b"ABUSE", b"ABYSS", b"ACORN", b"ACRID", b"ACTOR", b"ACUTE",
     b"ADAGE", b"ADAPT", b"ADEPT", b"ADMIN", b"ADMIT", b"ADOBE", b"ADOPT", b"ADORE", b"ADORN",
@@ -267,21 +270,702 @@ pub const WORDS: [&Word; WORD_COUNT] = [
     b"ZESTY", b"ZONAL",
 ];
 
-static WORD_SET: OnceLock<FxHashSet<&Word>> = OnceLock::new();
+pub const GUESS_COUNT: usize = 5965;
 
-fn init_word_set() -> FxHashSet<&'static Word> {
-    let mut word_set = FxHashSet::default();
-    for word in WORDS {
-        word_set.insert(word);
+/// Words that are allowed as a guess but are never picked as an answer.
+/// Disjoint from [`WORDS`].
+///
+/// This is a curated list, about half the size of the one the official game accepts, so some
+/// guesses it allows are rejected here.
+pub static GUESSES: [&Word; GUESS_COUNT] = [
+    b"AAHED", b"AALII", b"ABACI", b"ABAFT", b"ABAND", b"ABBAS", b"ABBES", b"ABEAM", b"ABELE",
+    b"ABETS", b"ABIES", b"ABLER", b"ABMHO", b"ABOMA", b"ABRAM", b"ABSIT", b"ACERB", b"ACHED",
+    b"ACHES", b"ACING", b"ACINI", b"ACKEE", b"ACMES", b"ACNED", b"ACNES", b"ACOLD", b"ACRED",
+    b"ACRES", b"ACTED", b"ACTIN", b"ACYLS", b"ADDAX", b"ADDED", b"ADDER", b"ADDLE", b"ADIEU",
+    b"ADIOS", b"ADMAN", b"ADMEN", b"ADOBO", b"ADOZE", b"ADUST", b"ADZED", b"ADZES", b"AECIA",
+    b"AEGIS", b"AEONS", b"AERIE", b"AFARS", b"AGAMA", b"AGARS", b"AGAVE", b"AGAZE", b"AGEES",
+    b"AGERS", b"AGGRO", b"AGHAS", b"AGIST", b"AGLEE", b"AGLET", b"AGLEY", b"AGLOO", b"AGMAS",
+    b"AGONE", b"AGONS", b"AHING", b"AHOLD", b"AIDED", b"AIDES", b"AILED", b"AIMED", b"AIOLI",
+    b"AIRED", b"AIRTH", b"AITCH", b"AIVER", b"AJIVA", b"AKELA", b"AKENE", b"ALAND", b"ALANE",
+    b"ALANS", b"ALANT", b"ALAPS", b"ALARY", b"ALATE", b"ALBAS", b"ALBEE", b"ALCID", b"ALDER",
+    b"ALECS", b"ALEFS", b"ALEPH", b"ALEWS", b"ALFAS", b"ALGAL", b"ALGAS", b"ALGID", b"ALGIN",
+    b"ALGOR", b"ALGUM", b"ALIAS", b"ALIFS", b"ALINE", b"ALIST", b"ALIYA", b"ALKYD", b"ALKYL",
+    b"ALLEE", b"ALLEL", b"ALLIS", b"ALLYL", b"ALMAH", b"ALMAS", b"ALMEH", b"ALMES", b"ALMUD",
+    b"ALMUG", b"ALOES", b"ALOHA", b"ALOIN", b"ALTOS", b"ALUMS", b"AMAHS", b"AMAIN", b"AMBOS",
+    b"AMEBA", b"AMEER", b"AMENS", b"AMENT", b"AMIAS", b"AMICE", b"AMIDE", b"AMIDO", b"AMIDS",
+    b"AMIES", b"AMIGA", b"AMIGO", b"AMINE", b"AMINO", b"AMINS", b"AMIRS", b"AMMOS", b"AMNIA",
+    b"AMNIC", b"AMOKS", b"AMOLE", b"AMPED", b"AMPUL", b"AMUCK", b"ANCON", b"ANEAR", b"ANELE",
+    b"ANENT", b"ANGAS", b"ANGLO", b"ANILE", b"ANILS", b"ANIMA", b"ANION", b"ANISE", b"ANKHS",
+    b"ANKUS", b"ANLAS", b"ANNAL", b"ANNAS", b"ANOAS", b"ANOLE", b"ANSAE", b"ANTAE", b"ANTAS",
+    b"ANTED", b"ANTES", b"ANTIS", b"ANTRA", b"ANTRE", b"ANTSY", b"APACE", b"APERS", b"APERY",
+    b"APIAN", b"APISH", b"APODS", b"APORT", b"APPAL", b"APPEL", b"APSES", b"APSIS", b"APTER",
+    b"ARAKS", b"ARBAS", b"ARCED", b"ARCUS", b"ARDEB", b"AREAE", b"AREAL", b"AREAS", b"ARECA",
+    b"AREIC", b"ARENE", b"AREPA", b"ARETE", b"ARGAL", b"ARGIL", b"ARGLE", b"ARGOL", b"ARGON",
+    b"ARGOT", b"ARGUS", b"ARHAT", b"ARIAS", b"ARIEL", b"ARILS", b"ARLES", b"ARMED", b"ARMET",
+    b"ARPEN", b"ARRAS", b"ARRIS", b"ARROZ", b"ARSES", b"ARSIS", b"ARTEL", b"ARUMS", b"ARVOS",
+    b"ASANA", b"ASCUS", b"ASDIC", b"ASHED", b"ASHES", b"ASKED", b"ASKER", b"ASKOI", b"ASKOS",
+    b"ASPEN", b"ASPER", b"ASPIC", b"ASPIS", b"ASSAI", b"ASSES", b"ASTER", b"ASTIR", b"ASYLA",
+    b"ATAPS", b"ATAXY", b"ATILT", b"ATMAN", b"ATMAS", b"ATOMS", b"ATONY", b"ATOPY", b"ATRIA",
+    b"ATRIP", b"ATTAR", b"AUDAD", b"AUGER", b"AUGHT", b"AULIC", b"AUNTS", b"AURAE", b"AURAL",
+    b"AURAR", b"AURAS", b"AUREI", b"AURES", b"AURIC", b"AURIS", b"AURUM", b"AUTOS", b"AUXIN",
+    b"AVAST", b"AVENS", b"AVERS", b"AVGAS", b"AVION", b"AVISO", b"AVOWS", b"AWING", b"AWNED",
+    b"AWOLS", b"AXELS", b"AXILS", b"AXING", b"AXITE", b"AXLED", b"AXLES", b"AXMAN", b"AXMEN",
+    b"AXONE", b"AXONS", b"AYAHS", b"AYINS", b"AZANS", b"AZIDE", b"AZIDO", b"AZINE", b"AZLON",
+    b"AZOIC", b"AZOLE", b"AZONS", b"AZOTE", b"AZOTH", b"BAAED", b"BAALS", b"BABAS", b"BABEL",
+    b"BABES", b"BABKA", b"BABOO", b"BABUL", b"BABUS", b"BACCA", b"BACKS", b"BADDY", b"BAFFS",
+    b"BAFFY", b"BAHTS", b"BAILS", b"BAIRN", b"BAITS", b"BAIZA", b"BAIZE", b"BAKED", b"BAKES",
+    b"BALAS", b"BALDS", b"BALDY", b"BALED", b"BALES", b"BALKS", b"BALKY", b"BALLS", b"BALLY",
+    b"BALMS", b"BALSA", b"BANCO", b"BANDS", b"BANED", b"BANES", b"BANGS", b"BANKS", b"BANNS",
+    b"BANTY", b"BARBE", b"BARBS", b"BARDE", b"BARDS", b"BARED", b"BARER", b"BARES", b"BARFS",
+    b"BARIC", b"BARKS", b"BARKY", b"BARMS", b"BARMY", b"BARNS", b"BARNY", b"BARRE", b"BASED",
+    b"BASER", b"BASES", b"BASKS", b"BASSI", b"BASSO", b"BASSY", b"BASTS", b"BATED", b"BATES",
+    b"BATHS", b"BATIK", b"BATTS", b"BAUDS", b"BAULK", b"BAWDS", b"BAWLS", b"BAYED", b"BAZAR",
+    b"BEADS", b"BEAKS", b"BEAKY", b"BEAMS", b"BEAMY", b"BEANO", b"BEANS", b"BEARS", b"BEATS",
+    b"BEAUS", b"BEAUT", b"BEAUX", b"BEBOP", b"BECAP", b"BECKS", b"BEDEW", b"BEDIM", b"BEEFS",
+    b"BEEPS", b"BEERS", b"BEERY", b"BEETS", b"BEFOG", b"BEGOT", b"BEGUM", b"BEIGE", b"BELAY",
+    b"BELGA", b"BELLS", b"BELTS", b"BEMAS", b"BEMIX", b"BENDS", b"BENDY", b"BENES", b"BENNE",
+    b"BENNI", b"BENNY", b"BENTS", b"BERGS", b"BERME", b"BERMS", b"BERYL", b"BESOM", b"BESOT",
+    b"BESTS", b"BETAS", b"BETHS", b"BETON", b"BEVOR", b"BEWIG", b"BEZIL", b"BHANG", b"BHOOT",
+    b"BHUTS", b"BIALI", b"BIALY", b"BIBBS", b"BICES", b"BIDED", b"BIDER", b"BIDES", b"BIDET",
+    b"BIELD", b"BIERS", b"BIFFS", b"BIFFY", b"BIFID", b"BIGHT", b"BIGLY", b"BIJOU", b"BIKED",
+    b"BIKER", b"BIKES", b"BIKIE", b"BILBO", b"BILES", b"BILGY", b"BILKS", b"BILLS", b"BIMAH",
+    b"BIMAS", b"BIMBO", b"BINAL", b"BINDI", b"BINDS", b"BINES", b"BINIT", b"BINTS", b"BIONT",
+    b"BIOTA", b"BIPED", b"BIPOD", b"BIRDS", b"BIRKS", b"BIRLE", b"BIRLS", b"BIRRS", b"BIRSE",
+    b"BISES", b"BISKS", b"BITCH", b"BITER", b"BITES", b"BITSY", b"BITTS", b"BIZES", b"BLABS",
+    b"BLAHS", b"BLAMS", b"BLASE", b"BLATS", b"BLAWN", b"BLAWS", b"BLEAR", b"BLEBS", b"BLENT",
+    b"BLEST", b"BLETS", b"BLIMY", b"BLINI", b"BLIPS", b"BLITE", b"BLOBS", b"BLOCS", b"BLOOP",
+    b"BLOTS", b"BLOWS", b"BLOWY", b"BLUED", b"BLUES", b"BLUEY", b"BLUME", b"BLURS", b"BLYPE",
+    b"BOARS", b"BOART", b"BOATS", b"BOCCE", b"BOCCI", b"BOCHE", b"BOCKS", b"BODED", b"BODES",
+    b"BOFFO", b"BOFFS", b"BOGAN", b"BOGEY", b"BOGGY", b"BOGIE", b"BOGLE", b"BOGUS", b"BOHEA",
+    b"BOILS", b"BOING", b"BOITE", b"BOLAR", b"BOLAS", b"BOLDS", b"BOLES", b"BOLLS", b"BOLOS",
+    b"BOLTS", b"BOLUS", b"BOMBE", b"BOMBS", b"BONDS", b"BONED", b"BONER", b"BONES", b"BONGS",
+    b"BONKS", b"BONNE", b"BONNY", b"BONZE", b"BOOBS", b"BOODY", b"BOOED", b"BOOGY", b"BOOKS",
+    b"BOOMS", b"BOOMY", b"BOONS", b"BOORS", b"BOOTS", b"BORAL", b"BORAS", b"BORED", b"BORER",
+    b"BORES", b"BORIC", b"BORON", b"BORTS", b"BORTY", b"BORTZ", b"BOSKS", b"BOSKY", b"BOSON",
+    b"BOSUN", b"BOTAS", b"BOTEL", b"BOTHY", b"BOTTS", b"BOURG", b"BOURN", b"BOUSE", b"BOUSY",
+    b"BOUTS", b"BOVID", b"BOWED", b"BOWER", b"BOWLS", b"BOWSE", b"BOXED", b"BOXES", b"BOYAR",
+    b"BOYLA", b"BOYOS", b"BOZOS", b"BRAAI", b"BRACT", b"BRADS", b"BRAES", b"BRAGS", b"BRAIL",
+    b"BRAKY", b"BRANK", b"BRANS", b"BRANT", b"BRATS", b"BRAVA", b"BRAVI", b"BRAWS", b"BRAXY",
+    b"BRAYS", b"BRAZA", b"BRAZE", b"BREAM", b"BREDE", b"BREES", b"BRENS", b"BRENT", b"BREVE",
+    b"BREWS", b"BRIER", b"BRIES", b"BRIGS", b"BRILL", b"BRIMS", b"BRIOS", b"BRITS", b"BROCK",
+    b"BROME", b"BROMO", b"BRONC", b"BROSE", b"BROSY", b"BROWS", b"BRUGH", b"BRUIN", b"BRUIT",
+    b"BRUME", b"BRUSK", b"BUBAL", b"BUBBA", b"BUBBY", b"BUBOS", b"BUCKO", b"BUCKS", b"BUFFI",
+    b"BUFFO", b"BUFFS", b"BUFFY", b"BUHLS", b"BUHRS", b"BULBS", b"BULGY", b"BULKS", b"BULLA",
+    b"BULLS", b"BUMFS", b"BUMPH", b"BUMPS", b"BUMPY", b"BUNCO", b"BUNDS", b"BUNDT", b"BUNGS",
+    b"BUNKO", b"BUNKS", b"BUNTS", b"BUOYS", b"BURAN", b"BURAS", b"BURBS", b"BURDS", b"BURET",
+    b"BURGH", b"BURGS", b"BURIN", b"BURKE", b"BURLS", b"BURNS", b"BURPS", b"BURRO", b"BURRS",
+    b"BURRY", b"BURSA", b"BURSE", b"BUSBY", b"BUSES", b"BUSKS", b"BUSTS", b"BUSTY", b"BUTEO",
+    b"BUTES", b"BUTLE", b"BUTTS", b"BUTTY", b"BUTUT", b"BUTYL", b"BWANA", b"BYRES", b"BYRLS",
+    b"BYSSI", b"BYTES", b"BYWAY", b"CAAED", b"CABER", b"CABOB", b"CACAS", b"CADES", b"CADGE",
+    b"CADGY", b"CADIS", b"CADRE", b"CAECA", b"CAFES", b"CAFFS", b"CAGED", b"CAGER", b"CAGES",
+    b"CAIDS", b"CAINS", b"CAIRD", b"CAJON", b"CAKED", b"CAKES", b"CAKEY", b"CALIF", b"CALIX",
+    b"CALKS", b"CALLA", b"CALLS", b"CALMS", b"CALVE", b"CALYX", b"CAMAS", b"CAMES", b"CAMOS",
+    b"CAMPI", b"CAMPO", b"CAMPS", b"CAMPY", b"CANED", b"CANER", b"CANES", b"CANID", b"CANNA",
+    b"CANSO", b"CANST", b"CANTO", b"CANTS", b"CANTY", b"CAPED", b"CAPES", b"CAPHS", b"CAPON",
+    b"CAPOS", b"CARBO", b"CARBS", b"CARDS", b"CARED", b"CARER", b"CARES", b"CARET", b"CAREX",
+    b"CARKS", b"CARLE", b"CARLS", b"CARNS", b"CARNY", b"CAROB", b"CAROM", b"CARPI", b"CARPS",
+    b"CARRS", b"CARSE", b"CARTE", b"CARTS", b"CASAS", b"CASED", b"CASES", b"CASKS", b"CASKY",
+    b"CASTS", b"CASUS", b"CATES", b"CAULD", b"CAULS", b"CAVED", b"CAVER", b"CAVES", b"CAVIE",
+    b"CAWED", b"CEBID", b"CECAL", b"CECUM", b"CEDED", b"CEDER", b"CEDES", b"CEDIS", b"CEIBA",
+    b"CEILS", b"CELEB", b"CELLA", b"CELLI", b"CELLS", b"CELOM", b"CELTS", b"CENSE", b"CENTO",
+    b"CENTS", b"CEORL", b"CEPES", b"CERCI", b"CERED", b"CERES", b"CERIA", b"CERIC", b"CEROS",
+    b"CESTA", b"CESTI", b"CETES", b"CHADS", b"CHAMS", b"CHANG", b"CHAPE", b"CHAPS", b"CHAPT",
+    b"CHARE", b"CHARK", b"CHARR", b"CHARS", b"CHARY", b"CHATS", b"CHAWS", b"CHAYS", b"CHEEP",
+    b"CHEFS", b"CHELA", b"CHEMO", b"CHERT", b"CHETH", b"CHEVY", b"CHEWS", b"CHEWY", b"CHIAO",
+    b"CHIAS", b"CHICO", b"CHICS", b"CHIEL", b"CHILE", b"CHIMB", b"CHIMP", b"CHINE", b"CHINK",
+    b"CHINO", b"CHINS", b"CHIPS", b"CHIRK", b"CHIRM", b"CHIRO", b"CHIRR", b"CHITS", b"CHIVE",
+    b"CHIVY", b"CHOKY", b"CHOLA", b"CHOLO", b"CHOMP", b"CHOOK", b"CHOPS", b"CHOTT", b"CHOUX",
+    b"CHOWS", b"CHUBS", b"CHUFA", b"CHUFF", b"CHUGS", b"CHUMS", b"CHURL", b"CHURR", b"CHYLE",
+    b"CHYME", b"CIBOL", b"CILIA", b"CIMEX", b"CINES", b"CIONS", b"CIRES", b"CIRRI", b"CISCO",
+    b"CISSY", b"CISTS", b"CITED", b"CITER", b"CITES", b"CIVET", b"CIVIE", b"CIVVY", b"CLACH",
+    b"CLADE", b"CLAGS", b"CLAMS", b"CLANS", b"CLAPS", b"CLAPT", b"CLARO", b"CLARY", b"CLAST",
+    b"CLAVE", b"CLAVI", b"CLAWS", b"CLAYS", b"CLEEK", b"CLEFS", b"CLEPE", b"CLEPT", b"CLEWS",
+    b"CLIFT", b"CLIME", b"CLINE", b"CLIPS", b"CLIPT", b"CLODS", b"CLOGS", b"CLOMB", b"CLOMP",
+    b"CLONK", b"CLOOT", b"CLOPS", b"CLOTS", b"CLOUR", b"CLOYS", b"CLOZE", b"CLUBS", b"CLUES",
+    b"CLUNK", b"COALS", b"COATS", b"CODES", b"COILS", b"COINS", b"COKES", b"COLAS", b"COLTS",
+    b"COMBS", b"COMES", b"CONES", b"COOKS", b"COOLS", b"COPES", b"CORDS", b"CORES", b"CORKS",
+    b"CORNS", b"COSTS", b"COUPS", b"COVES", b"CRABS", b"CREWS", b"CRIBS", b"CROPS", b"CROWS",
+    b"CUBES", b"CUFFS", b"CULTS", b"CURBS", b"CURDS", b"CURES", b"CURLS", b"DABBA", b"DACES",
+    b"DACHA", b"DADAS", b"DADOS", b"DAFFS", b"DAFFY", b"DAGGA", b"DAGOS", b"DAHLS", b"DALES",
+    b"DAMAN", b"DAMAR", b"DAMES", b"DAMNS", b"DAMPS", b"DANGS", b"DANIO", b"DARBS", b"DARED",
+    b"DARER", b"DARES", b"DARIC", b"DARKS", b"DARKY", b"DARNS", b"DARTS", b"DASHI", b"DASHY",
+    b"DATED", b"DATER", b"DATES", b"DATOS", b"DATTO", b"DAUBE", b"DAUBS", b"DAUBY", b"DAUTS",
+    b"DAVEN", b"DAVIT", b"DAWED", b"DAWEN", b"DAWKS", b"DAWNS", b"DAWTS", b"DAZED", b"DAZES",
+    b"DEADS", b"DEAIR", b"DEALS", b"DEANS", b"DEARS", b"DEARY", b"DEASH", b"DEAVE", b"DEBAG",
+    b"DEBTS", b"DEBYE", b"DECAF", b"DECKS", b"DEEDS", b"DEEDY", b"DEEMS", b"DEEPS", b"DEERS",
+    b"DEFAT", b"DEFIS", b"DEFOG", b"DEGAS", b"DEGUM", b"DEICE", b"DEIFY", b"DEILS", b"DEISM",
+    b"DEIST", b"DEKED", b"DEKES", b"DEKKO", b"DELED", b"DELES", b"DELFS", b"DELFT", b"DELIS",
+    b"DELLS", b"DELLY", b"DELTS", b"DEMES", b"DEMIC", b"DEMIT", b"DEMOB", b"DEMOS", b"DENAR",
+    b"DENES", b"DENTS", b"DEOXY", b"DERAT", b"DERAY", b"DERMA", b"DERMS", b"DERRY", b"DESKS",
+    b"DEVAS", b"DEVEL", b"DEVON", b"DEWAN", b"DEWAR", b"DEWAX", b"DEWED", b"DEXES", b"DEXIE",
+    b"DHAKS", b"DHALS", b"DHOLE", b"DHOTI", b"DHOWS", b"DHUTI", b"DIALS", b"DIAZO", b"DICED",
+    b"DICER", b"DICKS", b"DICKY", b"DICOT", b"DICTA", b"DICTY", b"DIDIE", b"DIDOS", b"DIDST",
+    b"DIENE", b"DIETS", b"DIGHT", b"DIKED", b"DIKER", b"DIKES", b"DIKEY", b"DILDO", b"DILLS",
+    b"DIMER", b"DIMES", b"DINAR", b"DINED", b"DINES", b"DINGE", b"DINGS", b"DINKS", b"DINKY",
+    b"DINTS", b"DIOLS", b"DIPPY", b"DIPSO", b"DIRER", b"DIRKS", b"DIRLS", b"DISCI", b"DISCS",
+    b"DISHY", b"DISKS", b"DISME", b"DITAS", b"DITES", b"DITSY", b"DITZY", b"DIVAN", b"DIVAS",
+    b"DIVED", b"DIVES", b"DIVOT", b"DIVVY", b"DIWAN", b"DIXIT", b"DIZEN", b"DJINN", b"DJINS",
+    b"DOATS", b"DOBBY", b"DOBIE", b"DOBLA", b"DOBRA", b"DOCKS", b"DODOS", b"DOERS", b"DOEST",
+    b"DOETH", b"DOFFS", b"DOGES", b"DOGEY", b"DOGGO", b"DOGGY", b"DOGIE", b"DOILY", b"DOITS",
+    b"DOJOS", b"DOLCE", b"DOLCI", b"DOLED", b"DOLES", b"DOLLS", b"DOLMA", b"DOLOR", b"DOLTS",
+    b"DOMAL", b"DOMED", b"DOMES", b"DOMIC", b"DONAS", b"DONEE", b"DONGA", b"DONGS", b"DONNA",
+    b"DONNE", b"DONSY", b"DOOMS", b"DOOMY", b"DOORS", b"DOOZY", b"DOPAS", b"DOPED", b"DOPER",
+    b"DOPES", b"DORKS", b"DORKY", b"DORMS", b"DORMY", b"DORPS", b"DORRS", b"DORSA", b"DORTY",
+    b"DOSED", b"DOSER", b"DOSES", b"DOTAL", b"DOTED", b"DOTER", b"DOTES", b"DOTTY", b"DOUCE",
+    b"DOULA", b"DOUMS", b"DOURA", b"DOUSE", b"DOVEN", b"DOVES", b"DOWED", b"DOWER", b"DOWIE",
+    b"DOWNS", b"DOWSE", b"DOXIE", b"DOYEN", b"DOYLY", b"DOZED", b"DOZER", b"DOZES", b"DRABS",
+    b"DRAFF", b"DRAGS", b"DRAIL", b"DRAMS", b"DRATS", b"DRAVE", b"DRAWS", b"DRAYS", b"DREAR",
+    b"DRECK", b"DREED", b"DREES", b"DREGS", b"DREKS", b"DREST", b"DRIBS", b"DRIES", b"DRILY",
+    b"DRIPS", b"DRIPT", b"DROID", b"DROPS", b"DROPT", b"DROUK", b"DRUBS", b"DRUGS", b"DRUMS",
+    b"DRUPE", b"DRUSE", b"DRYAD", b"DUADS", b"DUALS", b"DUCAL", b"DUCAT", b"DUCES", b"DUCKS",
+    b"DUCKY", b"DUCTS", b"DUDDY", b"DUDED", b"DUDES", b"DUELS", b"DUETS", b"DUFFS", b"DUITS",
+    b"DUKED", b"DUKES", b"DULIA", b"DULLS", b"DULSE", b"DUMAS", b"DUMBS", b"DUMKA", b"DUMKY",
+    b"DUMPS", b"DUNAM", b"DUNCH", b"DUNES", b"DUNGS", b"DUNGY", b"DUNKS", b"DUNTS", b"DUOMI",
+    b"DUOMO", b"DUPED", b"DUPER", b"DUPES", b"DUPLE", b"DURAL", b"DURAS", b"DURED", b"DURES",
+    b"DURNS", b"DUROC", b"DUROS", b"DURRA", b"DURRS", b"DURST", b"DURUM", b"DUSKS", b"DUSTS",
+    b"DWEEB", b"DWINE", b"DYADS", b"DYERS", b"DYKED", b"DYKES", b"DYKEY", b"DYNEL", b"DYNES",
+    b"EAGRE", b"EARED", b"EARLS", b"EARNS", b"EASED", b"EASES", b"EASTS", b"EAVED", b"EAVES",
+    b"EBBED", b"EBBET", b"EBONS", b"EBOOK", b"ECHED", b"ECHES", b"ECRUS", b"EDEMA", b"EDGED",
+    b"EDGER", b"EDGES", b"EDILE", b"EDITS", b"EDUCE", b"EDUCT", b"EGADS", b"EGERS", b"EGEST",
+    b"EGGAR", b"EGGED", b"EGGER", b"EIDER", b"EIDOS", b"EIKON", b"ELAND", b"ELANS", b"ELEMI",
+    b"ELUTE", b"ELVER", b"ELVES", b"EMBAY", b"EMBOW", b"EMEER", b"EMEND", b"EMERY", b"EMEUS",
+    b"EMIRS", b"EMITS", b"EMMER", b"EMMET", b"EMOTE", b"EMYDE", b"EMYDS", b"ENATE", b"ENDED",
+    b"ENDER", b"ENDUE", b"ENOKI", b"ENOLS", b"ENORM", b"ENOWS", b"ENROL", b"ENSKY", b"ENTIA",
+    b"ENURE", b"ENVOI", b"ENZYM", b"EOSIN", b"EPACT", b"EPEES", b"EPHAH", b"EPHAS", b"EPHOD",
+    b"EPHOR", b"EPICS", b"EPODE", b"EQUID", b"ERGOT", b"ERICA", b"ERNES", b"EROSE", b"ERRED",
+    b"ERSES", b"ERUCT", b"ESCAR", b"ESKER", b"ESSES", b"ESTOP", b"ETAPE", b"ETHYL", b"ETWEE",
+    b"ETYMA", b"EUROS", b"EVENS", b"EVERT", b"EVILS", b"EXAMS", b"EXECS", b"EXITS", b"EXPAT",
+    b"EXUDE", b"EXURB", b"EYERS", b"EYRAS", b"EYRES", b"EYRIE", b"EYRIR", b"FACED", b"FACER",
+    b"FACES", b"FACIA", b"FACTS", b"FADDY", b"FADED", b"FADER", b"FADES", b"FADGE", b"FAENA",
+    b"FAERY", b"FAGOT", b"FAILS", b"FAIRS", b"FAKED", b"FAKER", b"FAKES", b"FAKEY", b"FAKIR",
+    b"FALLS", b"FAMED", b"FANES", b"FANGA", b"FANGS", b"FANOS", b"FARAD", b"FARCI", b"FARCY",
+    b"FARDS", b"FARED", b"FARER", b"FARES", b"FARLE", b"FARLS", b"FARMS", b"FAROS", b"FARTS",
+    b"FASTS", b"FATED", b"FATES", b"FATLY", b"FATSO", b"FATWA", b"FAUGH", b"FAULD", b"FAUNS",
+    b"FAUVE", b"FAVAS", b"FAVES", b"FAVUS", b"FAWNS", b"FAWNY", b"FAXED", b"FAXES", b"FAYED",
+    b"FAZED", b"FAZES", b"FEARS", b"FEASE", b"FEATS", b"FEAZE", b"FECES", b"FECKS", b"FEEDS",
+    b"FEELS", b"FEEZE", b"FEINT", b"FEIST", b"FELID", b"FELLS", b"FELLY", b"FELTS", b"FEMES",
+    b"FENDS", b"FENNY", b"FEODS", b"FEOFF", b"FERES", b"FERIA", b"FERLY", b"FERMI", b"FERNS",
+    b"FERNY", b"FESSE", b"FETAS", b"FETED", b"FETES", b"FETOR", b"FEUAR", b"FEUDS", b"FEUED",
+    b"FEYER", b"FEYLY", b"FEZES", b"FIARS", b"FIATS", b"FICES", b"FICHE", b"FICHU", b"FICIN",
+    b"FIDGE", b"FIDOS", b"FIEFS", b"FIFED", b"FIFER", b"FIFES", b"FILAR", b"FILCH", b"FILED",
+    b"FILES", b"FILLE", b"FILLO", b"FILLS", b"FILMS", b"FILOS", b"FILUM", b"FINDS", b"FINED",
+    b"FINES", b"FINIS", b"FINKS", b"FINNY", b"FINOS", b"FIORD", b"FIQUE", b"FIRED", b"FIRER",
+    b"FIRES", b"FIRMS", b"FIRNS", b"FIRRY", b"FIRTH", b"FISCS", b"FISTS", b"FITCH", b"FITLY",
+    b"FIVER", b"FIVES", b"FIXED", b"FIXES", b"FIXIT", b"FJELD", b"FLABS", b"FLAGS", b"FLAKS",
+    b"FLAMS", b"FLAMY", b"FLANS", b"FLAPS", b"FLATS", b"FLAWS", b"FLAWY", b"FLAXY", b"FLAYS",
+    b"FLEAS", b"FLEER", b"FLEES", b"FLEWS", b"FLEYS", b"FLICS", b"FLIED", b"FLIES", b"FLIPS",
+    b"FLITE", b"FLITS", b"FLOCS", b"FLOES", b"FLOGS", b"FLONG", b"FLOPS", b"FLOTA", b"FLOWS",
+    b"FLUBS", b"FLUED", b"FLUES", b"FLUKY", b"FLUMP", b"FLUOR", b"FLUTY", b"FLUYT", b"FLYBY",
+    b"FLYTE", b"FOALS", b"FOAMS", b"FOEHN", b"FOGEY", b"FOGIE", b"FOHNS", b"FOILS", b"FOINS",
+    b"FOLDS", b"FOLIA", b"FOLKS", b"FOLKY", b"FONDS", b"FONDU", b"FONTS", b"FOODS", b"FOOLS",
+    b"FOOTS", b"FOOTY", b"FORAM", b"FORBS", b"FORBY", b"FORDO", b"FORDS", b"FORES", b"FORKS",
+    b"FORKY", b"FORME", b"FORMS", b"FORTS", b"FOSSA", b"FOSSE", b"FOULS", b"FOUNT", b"FOURS",
+    b"FOVEA", b"FOWLS", b"FOXED", b"FOXES", b"FRANC", b"FRAPS", b"FRASS", b"FRATS", b"FRAYS",
+    b"FREES", b"FRETS", b"FRIER", b"FRIES", b"FRIGS", b"FRISE", b"FRITH", b"FRITS", b"FRITT",
+    b"FRIZZ", b"FROES", b"FROGS", b"FRONS", b"FRORE", b"FROSH", b"FROWS", b"FRUGS", b"FRUMP",
+    b"FRYER", b"FUBSY", b"FUCKS", b"FUCUS", b"FUELS", b"FUGAL", b"FUGGY", b"FUGIO", b"FUGLE",
+    b"FUGUS", b"FUJIS", b"FULLS", b"FUMED", b"FUMER", b"FUMES", b"FUMET", b"FUNDI", b"FUNDS",
+    b"FUNGO", b"FUNKS", b"FURAN", b"FURLS", b"FURZE", b"FURZY", b"FUSED", b"FUSEE", b"FUSEL",
+    b"FUSES", b"FUSIL", b"FUSTY", b"FUTON", b"FUZED", b"FUZEE", b"FUZES", b"FUZIL", b"FYCES",
+    b"FYKES", b"FYTTE", b"GABBY", b"GABLE", b"GADDI", b"GADID", b"GADJE", b"GAFFS", b"GAGED",
+    b"GAGER", b"GAGES", b"GAINS", b"GAITS", b"GALAS", b"GALAX", b"GALEA", b"GALES", b"GALLS",
+    b"GALLY", b"GALOP", b"GAMAS", b"GAMAY", b"GAMBA", b"GAMBE", b"GAMBS", b"GAMED", b"GAMES",
+    b"GAMEY", b"GAMIC", b"GAMIN", b"GAMMY", b"GAMPS", b"GANEF", b"GANEV", b"GANGS", b"GANJA",
+    b"GANOF", b"GAOLS", b"GAPED", b"GAPER", b"GAPES", b"GAPPY", b"GARBS", b"GARDA", b"GARNI",
+    b"GARTH", b"GASES", b"GASPS", b"GASTS", b"GATED", b"GATER", b"GATES", b"GATOR", b"GAUDS",
+    b"GAULT", b"GAUMS", b"GAURS", b"GAUSS", b"GAUZY", b"GAVOT", b"GAWKS", b"GAWPS", b"GAWSY",
+    b"GAYAL", b"GAZAR", b"GAZED", b"GAZES", b"GEARS", b"GECKS", b"GEEKS", b"GEEST", b"GELDS",
+    b"GELEE", b"GELID", b"GELTS", b"GEMMA", b"GEMMY", b"GEMOT", b"GENES", b"GENET", b"GENIC",
+    b"GENII", b"GENIP", b"GENOA", b"GENOM", b"GENRO", b"GENTS", b"GENUA", b"GENUS", b"GEODE",
+    b"GEOID", b"GERAH", b"GERMS", b"GERMY", b"GESSO", b"GESTE", b"GESTS", b"GETAS", b"GETUP",
+    b"GEUMS", b"GHAST", b"GHATS", b"GHAUT", b"GHAZI", b"GHEES", b"GHYLL", b"GIBED", b"GIBER",
+    b"GIBES", b"GIFTS", b"GIGAS", b"GIGHE", b"GIGOT", b"GIGUE", b"GILDS", b"GILLS", b"GILLY",
+    b"GILTS", b"GIMEL", b"GIMME", b"GIMPS", b"GIMPY", b"GINNY", b"GIPON", b"GIRDS", b"GIRLS",
+    b"GIRNS", b"GIRON", b"GIROS", b"GIRSH", b"GIRTS", b"GISMO", b"GISTS", b"GITES", b"GIVES",
+    b"GIZMO", b"GLACE", b"GLADS", b"GLADY", b"GLAIR", b"GLANS", b"GLARY", b"GLAZY", b"GLEBA",
+    b"GLEBE", b"GLEDE", b"GLEDS", b"GLEED", b"GLEEK", b"GLEES", b"GLEET", b"GLENS", b"GLEYS",
+    b"GLIAL", b"GLIAS", b"GLIFF", b"GLIME", b"GLITZ", b"GLOAM", b"GLOBS", b"GLOGG", b"GLOMS",
+    b"GLOPS", b"GLOST", b"GLOUT", b"GLOWS", b"GLOZE", b"GLUED", b"GLUER", b"GLUES", b"GLUEY",
+    b"GLUGS", b"GLUME", b"GLUON", b"GLUTS", b"GNARL", b"GNARR", b"GNARS", b"GNATS", b"GNAWN",
+    b"GNAWS", b"GOADS", b"GOALS", b"GOATS", b"GOBAN", b"GOBOS", b"GODET", b"GOERS", b"GOFER",
+    b"GOGOS", b"GOLDS", b"GOLFS", b"GOMBO", b"GONEF", b"GONGS", b"GONIA", b"GONIF", b"GONOF",
+    b"GONZO", b"GOODS", b"GOOFS", b"GOOKS", b"GOOKY", b"GOONS", b"GOONY", b"GOOPS", b"GOOPY",
+    b"GOOSY", b"GORAL", b"GORED", b"GORES", b"GORPS", b"GORSE", b"GORSY", b"GOUTS", b"GOUTY",
+    b"GOWAN", b"GOWDS", b"GOWKS", b"GOWNS", b"GOXES", b"GOYIM", b"GRAAL", b"GRABS", b"GRADS",
+    b"GRAMA", b"GRAMP", b"GRAMS", b"GRANA", b"GRANS", b"GRAPY", b"GRAYS", b"GREBE", b"GREEK",
+    b"GREGO", b"GREYS", b"GRIDE", b"GRIDS", b"GRIFF", b"GRIFT", b"GRIGS", b"GRINS", b"GRIOT",
+    b"GRIPS", b"GRIPT", b"GRIPY", b"GRIST", b"GRITH", b"GRITS", b"GROAT", b"GROGS", b"GROSZ",
+    b"GROTS", b"GROWS", b"GRUBS", b"GRUME", b"GRUMP", b"GUACO", b"GUANO", b"GUANS", b"GUARS",
+    b"GUCKS", b"GUDES", b"GUFFS", b"GUIRO", b"GULAG", b"GULAR", b"GULES", b"GULFS", b"GULFY",
+    b"GULLS", b"GULPS", b"GULPY", b"GUMMA", b"GUNKS", b"GUNKY", b"GUNNY", b"GURGE", b"GURNS",
+    b"GURRY", b"GURSH", b"GURUS", b"GUSHY", b"GUSSY", b"GUSTS", b"GUTSY", b"GUTTA", b"GUTTY",
+    b"GUYED", b"GUYOT", b"GYBED", b"GYBES", b"GYRAL", b"GYRED", b"GYRES", b"GYROS", b"GYRUS",
+    b"GYVED", b"GYVES", b"HABUS", b"HACEK", b"HACKS", b"HADAL", b"HADED", b"HADES", b"HADJI",
+    b"HADST", b"HAEMS", b"HAETS", b"HAFIZ", b"HAFTS", b"HAHAS", b"HAIKA", b"HAIKS", b"HAIKU",
+    b"HAILS", b"HAIRS", b"HAJES", b"HAJIS", b"HAJJI", b"HAKES", b"HAKIM", b"HALED", b"HALER",
+    b"HALES", b"HALID", b"HALLO", b"HALLS", b"HALMA", b"HALMS", b"HALOS", b"HALTS", b"HALVA",
+    b"HAMAL", b"HAMES", b"HAMMY", b"HAMZA", b"HANCE", b"HANDS", b"HANGS", b"HANKS", b"HANKY",
+    b"HANSA", b"HANSE", b"HANTS", b"HAOLE", b"HAPAX", b"HAPLY", b"HARDS", b"HARED", b"HARES",
+    b"HARKS", b"HARLS", b"HARMS", b"HARPS", b"HARTS", b"HASPS", b"HATED", b"HATES", b"HAULM",
+    b"HAULS", b"HAVER", b"HAVES", b"HAWED", b"HAWKS", b"HAWSE", b"HAYED", b"HAYER", b"HAZAN",
+    b"HAZED", b"HAZER", b"HAZES", b"HEADS", b"HEALS", b"HEAPS", b"HEARS", b"HEATS", b"HEBES",
+    b"HECKS", b"HEDER", b"HEDGY", b"HEEDS", b"HEELS", b"HEEZE", b"HEFTS", b"HEIGH", b"HEILS",
+    b"HEIRS", b"HELIO", b"HELLS", b"HELMS", b"HELOS", b"HELOT", b"HELPS", b"HELVE", b"HEMAL",
+    b"HEMES", b"HEMIC", b"HEMIN", b"HEMPS", b"HEMPY", b"HENNA", b"HENRY", b"HENTS", b"HERBS",
+    b"HERBY", b"HERDS", b"HERLS", b"HERMA", b"HERMS", b"HERNS", b"HERRY", b"HERTZ", b"HESTS",
+    b"HETHS", b"HEUCH", b"HEUGH", b"HEWED", b"HEWER", b"HEXAD", b"HEXED", b"HEXER", b"HEXES",
+    b"HEXYL", b"HICKS", b"HIDED", b"HIDER", b"HIDES", b"HIKED", b"HIKER", b"HIKES", b"HILAR",
+    b"HILLO", b"HILLS", b"HILTS", b"HILUM", b"HILUS", b"HINDS", b"HINNY", b"HINTS", b"HIRED",
+    b"HIRER", b"HIRES", b"HISSY", b"HISTS", b"HIVED", b"HIVES", b"HOAGY", b"HOARS", b"HOARY",
+    b"HOBOS", b"HOCKS", b"HOCUS", b"HODAD", b"HOERS", b"HOGAN", b"HOGGS", b"HOICK", b"HOISE",
+    b"HOKED", b"HOKES", b"HOKEY", b"HOKKU", b"HOKUM", b"HOLDS", b"HOLED", b"HOLES", b"HOLLA",
+    b"HOLLO", b"HOLMS", b"HOLTS", b"HOMED", b"HOMES", b"HOMEY", b"HOMIE", b"HOMOS", b"HONAN",
+    b"HONDA", b"HONED", b"HONER", b"HONES", b"HONGS", b"HONKS", b"HONKY", b"HOOCH", b"HOODS",
+    b"HOODY", b"HOOEY", b"HOOFS", b"HOOKA", b"HOOKS", b"HOOKY", b"HOOLY", b"HOOPS", b"HOOTS",
+    b"HOOTY", b"HOPED", b"HOPER", b"HOPES", b"HOPPY", b"HORAH", b"HORAL", b"HORAS", b"HORNS",
+    b"HORST", b"HORSY", b"HOSED", b"HOSEL", b"HOSEN", b"HOSES", b"HOSTA", b"HOSTS", b"HOTCH",
+    b"HOURI", b"HOURS", b"HOWES", b"HOWFF", b"HOWFS", b"HOWKS", b"HOWLS", b"HOYAS", b"HOYLE",
+    b"HUBBY", b"HUCKS", b"HUFFS", b"HUFFY", b"HUGER", b"HULAS", b"HULKS", b"HULKY", b"HULLO",
+    b"HULLS", b"HUMPS", b"HUMPY", b"HUNKS", b"HUNTS", b"HURDS", b"HURLS", b"HURLY", b"HURST",
+    b"HURTS", b"HUSKS", b"HUZZA", b"HYDRA", b"HYING", b"HYLAS", b"HYMNS", b"HYOID", b"HYPED",
+    b"HYPES", b"HYPHA", b"HYPOS", b"HYRAX", b"HYSON", b"ICHOR", b"ICIER", b"ICKER", b"ICONS",
+    b"ICTIC", b"ICTUS", b"IDEAS", b"IDLED", b"IDLES", b"IDOLS", b"IDYLS", b"IGLUS", b"IHRAM",
+    b"IKATS", b"IKONS", b"ILEAC", b"ILEAL", b"ILEUM", b"ILEUS", b"ILIAD", b"ILIAL", b"ILIUM",
+    b"ILLER", b"IMAGO", b"IMAMS", b"IMAUM", b"IMBED", b"IMIDE", b"IMIDO", b"IMIDS", b"IMINE",
+    b"IMINO", b"IMMIX", b"IMPED", b"IMPIS", b"INAPT", b"INARM", b"INBYE", b"INCOG", b"INCUS",
+    b"INDIE", b"INDOL", b"INDOW", b"INDRI", b"INDUE", b"INFIX", b"INFOS", b"INFRA", b"INGLE",
+    b"INKED", b"INKER", b"INKLE", b"INNED", b"INSET", b"INTIS", b"INURE", b"INURN", b"INVAR",
+    b"IODIC", b"IODID", b"IODIN", b"IOTAS", b"IRADE", b"IRIDS", b"IRING", b"IRKED", b"IROKO",
+    b"IRONE", b"IRONS", b"ISBAS", b"ISLED", b"ISLES", b"ISSEI", b"ISTLE", b"ITEMS", b"IVIED",
+    b"IVIES", b"IXIAS", b"IXORA", b"IZARS", b"JABOT", b"JACAL", b"JACKS", b"JACKY", b"JADED",
+    b"JADES", b"JAGER", b"JAGGS", b"JAGGY", b"JAGRA", b"JAILS", b"JAKES", b"JALAP", b"JALOP",
+    b"JAMBE", b"JAMBS", b"JAMMY", b"JANES", b"JANTY", b"JAPAN", b"JAPED", b"JAPER", b"JAPES",
+    b"JARLS", b"JATOS", b"JAUKS", b"JAUPS", b"JAVAS", b"JAWAN", b"JAWED", b"JEANS", b"JEBEL",
+    b"JEEPS", b"JEERS", b"JEFES", b"JEHAD", b"JEHUS", b"JELLS", b"JEMMY", b"JENNY", b"JERID",
+    b"JERKS", b"JERRY", b"JESTS", b"JETES", b"JETON", b"JIBBS", b"JIBED", b"JIBER", b"JIBES",
+    b"JIFFS", b"JIHAD", b"JILLS", b"JILTS", b"JIMMY", b"JIMPY", b"JINGO", b"JINKS", b"JINNI",
+    b"JINNS", b"JIVED", b"JIVER", b"JIVES", b"JIVEY", b"JNANA", b"JOCKO", b"JOCKS", b"JOEYS",
+    b"JOHNS", b"JOINS", b"JOKED", b"JOKES", b"JOKEY", b"JOLES", b"JOLTS", b"JOLTY", b"JONES",
+    b"JORAM", b"JORUM", b"JOTAS", b"JOTTY", b"JOUAL", b"JOUKS", b"JOULE", b"JOWED", b"JOWLS",
+    b"JOWLY", b"JOYED", b"JUBAS", b"JUBES", b"JUCOS", b"JUDAS", b"JUDOS", b"JUGAL", b"JUGUM",
+    b"JUJUS", b"JUKED", b"JUKES", b"JULEP", b"JUMPS", b"JUNCO", b"JUNKS", b"JUNKY", b"JUPES",
+    b"JUPON", b"JURAL", b"JURAT", b"JUREL", b"JUSTS", b"JUTES", b"JUTTY", b"KABAB", b"KABAR",
+    b"KABOB", b"KADIS", b"KAFIR", b"KAGUS", b"KAIAK", b"KAIFS", b"KAILS", b"KAINS", b"KAKAS",
+    b"KAKIS", b"KALAM", b"KALES", b"KALIF", b"KALPA", b"KAMES", b"KAMIK", b"KANAS", b"KANES",
+    b"KANJI", b"KAONS", b"KAPAS", b"KAPHS", b"KAPOK", b"KAPUT", b"KARAT", b"KARNS", b"KAROO",
+    b"KARST", b"KARTS", b"KASHA", b"KATAS", b"KAURI", b"KAURY", b"KAVAS", b"KAYOS", b"KAZOO",
+    b"KBARS", b"KEBAR", b"KEBOB", b"KECKS", b"KEDGE", b"KEEFS", b"KEEKS", b"KEELS", b"KEENS",
+    b"KEEPS", b"KEETS", b"KEEVE", b"KEFIR", b"KEIRS", b"KELIM", b"KELLY", b"KELPS", b"KELPY",
+    b"KEMPS", b"KEMPT", b"KENAF", b"KENCH", b"KENDO", b"KENOS", b"KEPIS", b"KERBS", b"KERFS",
+    b"KERNE", b"KERNS", b"KERRY", b"KETCH", b"KETOL", b"KEVEL", b"KEVIL", b"KEXES", b"KEYED",
+    b"KHADI", b"KHAFS", b"KHANS", b"KHAPH", b"KHATS", b"KHEDA", b"KHETH", b"KHETS", b"KHOUM",
+    b"KIANG", b"KIBBE", b"KIBBI", b"KIBEI", b"KIBES", b"KIBLA", b"KICKS", b"KICKY", b"KIDDO",
+    b"KIDDY", b"KIEFS", b"KIERS", b"KIKES", b"KILIM", b"KILLS", b"KILNS", b"KILOS", b"KILTS",
+    b"KILTY", b"KINDA", b"KINDS", b"KINES", b"KINGS", b"KININ", b"KINKS", b"KINOS", b"KIRKS",
+    b"KIRNS", b"KISSY", b"KISTS", b"KITED", b"KITER", b"KITES", b"KITHE", b"KITHS", b"KIVAS",
+    b"KIWIS", b"KLONG", b"KLOOF", b"KLUGE", b"KLUTZ", b"KNAPS", b"KNARS", b"KNAUR", b"KNEES",
+    b"KNELL", b"KNISH", b"KNITS", b"KNOBS", b"KNOPS", b"KNOSP", b"KNOTS", b"KNOUT", b"KNOWS",
+    b"KNURL", b"KNURS", b"KOANS", b"KOBOS", b"KOELS", b"KOHLS", b"KOINE", b"KOLAS", b"KOLOS",
+    b"KONKS", b"KOOKS", b"KOOKY", b"KOPEK", b"KOPHS", b"KOPJE", b"KOPPA", b"KORAI", b"KORAS",
+    b"KORAT", b"KORUN", b"KOTOS", b"KOTOW", b"KRAAL", b"KRAFT", b"KRAIT", b"KRAUT", b"KREEP",
+    b"KRONA", b"KRONE", b"KROON", b"KRUBI", b"KUDOS", b"KUDUS", b"KUDZU", b"KUGEL", b"KUKRI",
+    b"KULAK", b"KUMYS", b"KURTA", b"KURUS", b"KUSSO", b"KVASS", b"KYACK", b"KYAKS", b"KYARS",
+    b"KYATS", b"KYTES", b"KYTHE", b"LAARI", b"LABIA", b"LABRA", b"LACED", b"LACER", b"LACES",
+    b"LACEY", b"LACKS", b"LADED", b"LADER", b"LADES", b"LAEVO", b"LAGAN", b"LAHAR", b"LAICH",
+    b"LAICS", b"LAIGH", b"LAIRD", b"LAIRS", b"LAITH", b"LAITY", b"LAKED", b"LAKER", b"LAKES",
+    b"LAKHS", b"LALLS", b"LAMAS", b"LAMBS", b"LAMBY", b"LAMED", b"LAMER", b"LAMES", b"LAMIA",
+    b"LAMPS", b"LANAI", b"LANDS", b"LANES", b"LAPIN", b"LAPIS", b"LARCH", b"LARDS", b"LARDY",
+    b"LAREE", b"LARES", b"LARGO", b"LARIS", b"LARKS", b"LARKY", b"LARUM", b"LASED", b"LASER",
+    b"LASES", b"LASTS", b"LATED", b"LATEN", b"LATEX", b"LATHI", b"LATHS", b"LATHY", b"LATKE",
+    b"LAUAN", b"LAUDS", b"LAURA", b"LAVAS", b"LAVED", b"LAVER", b"LAVES", b"LAWNS", b"LAWNY",
+    b"LAXER", b"LAXLY", b"LAYED", b"LAYUP", b"LAZAR", b"LAZED", b"LAZES", b"LEADS", b"LEADY",
+    b"LEAFS", b"LEAKS", b"LEANS", b"LEAPS", b"LEARS", b"LEARY", b"LEAVY", b"LEAZE", b"LEDGY",
+    b"LEEKS", b"LEERS", b"LEFTS", b"LEGER", b"LEGES", b"LEGIT", b"LEHRS", b"LEHUA", b"LEMAN",
+    b"LEMMA", b"LENDS", b"LENES", b"LENIS", b"LENOS", b"LENSE", b"LENTO", b"LEONE", b"LEPTA",
+    b"LETCH", b"LETHE", b"LETUP", b"LEUDS", b"LEVEE", b"LEVIN", b"LEWIS", b"LEXES", b"LEXIS",
+    b"LIANA", b"LIANE", b"LIANG", b"LIARD", b"LIARS", b"LIBER", b"LIBRA", b"LIBRI", b"LICHI",
+    b"LICHT", b"LICIT", b"LICKS", b"LIDAR", b"LIDOS", b"LIENS", b"LIERS", b"LIEUS", b"LIEVE",
+    b"LIFER", b"LIFTS", b"LIGAN", b"LIGER", b"LIKED", b"LIKER", b"LIKES", b"LILTS", b"LIMAN",
+    b"LIMAS", b"LIMBA", b"LIMBI", b"LIMBS", b"LIMBY", b"LIMED", b"LIMEN", b"LIMES", b"LIMEY",
+    b"LIMNS", b"LIMOS", b"LIMPA", b"LIMPS", b"LINAC", b"LINDY", b"LINED", b"LINES", b"LINEY",
+    b"LINGA", b"LINGS", b"LINGY", b"LININ", b"LINKS", b"LINKY", b"LINNS", b"LINOS", b"LINTS",
+    b"LINTY", b"LINUM", b"LIONS", b"LIPIN", b"LIPPY", b"LIRAS", b"LIROT", b"LISLE", b"LISPS",
+    b"LISTS", b"LITAI", b"LITAS", b"LITER", b"LITHO", b"LITHS", b"LITRE", b"LIVED", b"LIVEN",
+    b"LIVES", b"LIVRE", b"LLANO", b"LOACH", b"LOADS", b"LOAFS", b"LOAMS", b"LOANS", b"LOBAR",
+    b"LOBED", b"LOBES", b"LOBOS", b"LOCHS", b"LOCKS", b"LOCOS", b"LOCUM", b"LODEN", b"LODES",
+    b"LOESS", b"LOFTS", b"LOGAN", b"LOGES", b"LOGGY", b"LOGIA", b"LOGOI", b"LOGOS", b"LOINS",
+    b"LOLLS", b"LOLLY", b"LONER", b"LONGE", b"LONGS", b"LOOBY", b"LOOED", b"LOOEY", b"LOOFA",
+    b"LOOFS", b"LOOIE", b"LOOKS", b"LOOMS", b"LOONS", b"LOONY", b"LOOPS", b"LOOTS", b"LOPED",
+    b"LOPER", b"LOPES", b"LOPPY", b"LORAL", b"LORAN", b"LORDS", b"LORES", b"LORIS", b"LOSEL",
+    b"LOSES", b"LOSSY", b"LOTAH", b"LOTAS", b"LOTIC", b"LOTOS", b"LOTTE", b"LOTTO", b"LOTUS",
+    b"LOUGH", b"LOUIE", b"LOUIS", b"LOUPE", b"LOUPS", b"LOURS", b"LOURY", b"LOUTS", b"LOVAT",
+    b"LOVED", b"LOVES", b"LOWED", b"LOWES", b"LOWSE", b"LOXED", b"LOXES", b"LUAUS", b"LUBES",
+    b"LUCES", b"LUCKS", b"LUCRE", b"LUDES", b"LUDIC", b"LUFFA", b"LUFFS", b"LUGED", b"LUGER",
+    b"LUGES", b"LULLS", b"LULUS", b"LUMPS", b"LUNAS", b"LUNES", b"LUNET", b"LUNGI", b"LUNGS",
+    b"LUNKS", b"LUNTS", b"LUPIN", b"LURED", b"LURER", b"LURES", b"LURKS", b"LUSTS", b"LUSUS",
+    b"LUTEA", b"LUTED", b"LUTES", b"LUXES", b"LWEIS", b"LYARD", b"LYART", b"LYASE", b"LYCEA",
+    b"LYCEE", b"LYRES", b"LYSED", b"LYSES", b"LYSIN", b"LYSIS", b"LYSSA", b"LYTIC", b"LYTTA",
+    b"MACED", b"MACER", b"MACES", b"MACHE", b"MACHS", b"MACKS", b"MACLE", b"MACON", b"MADRE",
+    b"MAFIC", b"MAGES", b"MAGOT", b"MAGUS", b"MAHOE", b"MAIDS", b"MAILE", b"MAILL", b"MAILS",
+    b"MAIMS", b"MAIST", b"MAKAR", b"MAKES", b"MAKOS", b"MALAR", b"MALES", b"MALIC", b"MALLS",
+    b"MALMS", b"MALMY", b"MALTS", b"MALTY", b"MAMAS", b"MAMBA", b"MAMEY", b"MAMIE", b"MANAS",
+    b"MANED", b"MANES", b"MANNA", b"MANOS", b"MANSE", b"MANTA", b"MANUS", b"MAQUI", b"MARAS",
+    b"MARCS", b"MARES", b"MARGE", b"MARIA", b"MARKS", b"MARLS", b"MARLY", b"MARSE", b"MARTS",
+    b"MARVY", b"MASER", b"MASHY", b"MASKS", b"MASSA", b"MASSY", b"MASTS", b"MATED", b"MATER",
+    b"MATES", b"MATHS", b"MATIN", b"MATTE", b"MATTS", b"MATZA", b"MATZO", b"MAULS", b"MAUND",
+    b"MAUTS", b"MAVEN", b"MAVIE", b"MAVIN", b"MAVIS", b"MAWED", b"MAXES", b"MAXIS", b"MAYAN",
+    b"MAYAS", b"MAYED", b"MAYOS", b"MAYST", b"MAZED", b"MAZER", b"MAZES", b"MEADS", b"MEALS",
+    b"MEANS", b"MEANY", b"MEATS", b"MEDII", b"MEEDS", b"MEETS", b"MEINY", b"MELDS", b"MELIC",
+    b"MELLS", b"MELTS", b"MEMES", b"MEMOS", b"MENAD", b"MENDS", b"MENSA", b"MENSE", b"MENTA",
+    b"MENUS", b"MEOUS", b"MEOWS", b"MERCS", b"MERER", b"MERES", b"MERLE", b"MERLS", b"MESAS",
+    b"MESHY", b"MESIC", b"MESNE", b"MESON", b"MESSY", b"METED", b"METES", b"METHS", b"METIS",
+    b"METRE", b"MEWED", b"MEWLS", b"MEZES", b"MEZZO", b"MIAOU", b"MIAOW", b"MIASM", b"MIAUL",
+    b"MICAS", b"MICHE", b"MICKS", b"MICRA", b"MIDDY", b"MIDIS", b"MIENS", b"MIFFS", b"MIFFY",
+    b"MIGGS", b"MIHAS", b"MIKED", b"MIKES", b"MIKRA", b"MILCH", b"MILDS", b"MILER", b"MILES",
+    b"MILIA", b"MILKS", b"MILLE", b"MILLS", b"MILOS", b"MILPA", b"MILTS", b"MILTY", b"MIMED",
+    b"MIMEO", b"MIMER", b"MIMES", b"MIMSY", b"MINAE", b"MINAS", b"MINCY", b"MINDS", b"MINED",
+    b"MINES", b"MINGY", b"MINIS", b"MINKE", b"MINKS", b"MINNY", b"MINTS", b"MIRED", b"MIRES",
+    b"MIREX", b"MIRKS", b"MIRKY", b"MIRZA", b"MISDO", b"MISES", b"MISOS", b"MISTS", b"MISTY",
+    b"MITER", b"MITES", b"MITIS", b"MITRE", b"MITTS", b"MIXED", b"MIXER", b"MIXES", b"MIXUP",
+    b"MIZEN", b"MOANS", b"MOATS", b"MOCKS", b"MODES", b"MODUS", b"MOGGY", b"MOHEL", b"MOHUR",
+    b"MOILS", b"MOIRA", b"MOIRE", b"MOKES", b"MOLAL", b"MOLAS", b"MOLDS", b"MOLES", b"MOLLS",
+    b"MOLLY", b"MOLTO", b"MOLTS", b"MOMES", b"MOMMA", b"MOMMY", b"MONAD", b"MONAS", b"MONDE",
+    b"MONDO", b"MONGO", b"MONIE", b"MONKS", b"MONTE", b"MOOCH", b"MOODS", b"MOOED", b"MOOLA",
+    b"MOOLS", b"MOONS", b"MOONY", b"MOORS", b"MOORY", b"MOOTS", b"MOPED", b"MOPER", b"MOPES",
+    b"MOPEY", b"MORAE", b"MORAS", b"MORAY", b"MOREL", b"MORES", b"MORNS", b"MORRO", b"MORSE",
+    b"MORTS", b"MOSEY", b"MOSKS", b"MOSSO", b"MOSTE", b"MOSTS", b"MOTES", b"MOTET", b"MOTEY",
+    b"MOTHS", b"MOTHY", b"MOTTE", b"MOTTS", b"MOUCH", b"MOUES", b"MOULD", b"MOUSY", b"MOVED",
+    b"MOVES", b"MOWED", b"MOXAS", b"MOXIE", b"MOZOS", b"MUCID", b"MUCIN", b"MUCKS", b"MUCOR",
+    b"MUCRO", b"MUDRA", b"MUFFS", b"MUFTI", b"MUGGS", b"MUGGY", b"MUHLY", b"MUJIK", b"MULCT",
+    b"MULED", b"MULES", b"MULEY", b"MULLA", b"MULLS", b"MUMMS", b"MUMPS", b"MUMUS", b"MUNGO",
+    b"MUNIS", b"MUONS", b"MURAS", b"MURED", b"MURES", b"MUREX", b"MURID", b"MURKS", b"MURRA",
+    b"MURRE", b"MURRS", b"MURRY", b"MUSED", b"MUSER", b"MUSES", b"MUSKS", b"MUSSY", b"MUSTH",
+    b"MUSTS", b"MUTCH", b"MUTED", b"MUTER", b"MUTES", b"MUTON", b"MUTTS", b"MUZZY", b"MYLAR",
+    b"MYNAH", b"MYNAS", b"MYOID", b"MYOMA", b"MYOPE", b"MYOPY", b"MYSID", b"MYTHS", b"MYTHY",
+    b"NAANS", b"NABES", b"NABIS", b"NABOB", b"NACHO", b"NACRE", b"NADAS", b"NAEVI", b"NAGGY",
+    b"NAIAD", b"NAIFS", b"NAILS", b"NAIRA", b"NAKED", b"NALED", b"NAMED", b"NAMER", b"NAMES",
+    b"NANAS", b"NAPAS", b"NAPES", b"NAPPE", b"NAPPY", b"NARCO", b"NARCS", b"NARDS", b"NARES",
+    b"NARIC", b"NARIS", b"NARKS", b"NARKY", b"NATCH", b"NATES", b"NATTY", b"NAVAR", b"NAVES",
+    b"NAVVY", b"NAWAB", b"NAZIS", b"NEAPS", b"NEARS", b"NEATH", b"NEATS", b"NECKS", b"NEEDS",
+    b"NEEMS", b"NEIFS", b"NEIST", b"NELLY", b"NEMAS", b"NENES", b"NEONS", b"NERDS", b"NEROL",
+    b"NERTS", b"NERTZ", b"NERVY", b"NESTS", b"NETOP", b"NETTS", b"NETTY", b"NEUKS", b"NEUME",
+    b"NEUMS", b"NEVES", b"NEVUS", b"NEWEL", b"NEWIE", b"NEWSY", b"NEWTS", b"NEXUS", b"NGWEE",
+    b"NICAD", b"NICKS", b"NIDAL", b"NIDED", b"NIDES", b"NIDUS", b"NIEVE", b"NIFFY", b"NIFTY",
+    b"NIHIL", b"NILLS", b"NIMBI", b"NINES", b"NINON", b"NIPAS", b"NIPPY", b"NISEI", b"NISUS",
+    b"NITER", b"NITES", b"NITID", b"NITON", b"NITRE", b"NITRO", b"NITTY", b"NIVAL", b"NIXED",
+    b"NIXES", b"NIXIE", b"NIZAM", b"NOBBY", b"NOCKS", b"NODAL", b"NODDY", b"NODES", b"NODUS",
+    b"NOELS", b"NOGGS", b"NOHOW", b"NOILS", b"NOILY", b"NOIRS", b"NOLOS", b"NOMAS", b"NOMEN",
+    b"NOMES", b"NOMOI", b"NOMOS", b"NONAS", b"NONCE", b"NONES", b"NONET", b"NONYL", b"NOOKS",
+    b"NOOKY", b"NOONS", b"NOPAL", b"NORIA", b"NORIS", b"NORMS", b"NOSED", b"NOSES", b"NOTAL",
+    b"NOTED", b"NOTER", b"NOTES", b"NOTUM", b"NOUNS", b"NOVAE", b"NOVAS", b"NOWAY", b"NOWTS",
+    b"NUBBY", b"NUDER", b"NUDES", b"NUDIE", b"NUKED", b"NUKES", b"NULLS", b"NUMBS", b"NURDS",
+    b"NUTSY", b"NYALA", b"OAKUM", b"OARED", b"OASES", b"OASIS", b"OASTS", b"OATEN", b"OATER",
+    b"OATHS", b"OAVES", b"OBEAH", b"OBELI", b"OBEYS", b"OBIAS", b"OBITS", b"OBJET", b"OBOES",
+    b"OBOLE", b"OBOLI", b"OBOLS", b"OCHER", b"OCHES", b"OCHRE", b"OCHRY", b"OCKER", b"OCREA",
+    b"OCTAD", b"OCTAN", b"OCTYL", b"OCULI", b"ODEON", b"ODEUM", b"ODIST", b"ODIUM", b"ODORS",
+    b"ODOUR", b"ODYLE", b"ODYLS", b"OFFED", b"OFTER", b"OGAMS", b"OGEES", b"OGHAM", b"OGIVE",
+    b"OGLED", b"OGLER", b"OGLES", b"OGRES", b"OHIAS", b"OHING", b"OHMIC", b"OIDIA", b"OILED",
+    b"OILER", b"OINKS", b"OKAPI", b"OKAYS", b"OKEHS", b"OKRAS", b"OLDIE", b"OLEIC", b"OLEIN",
+    b"OLEOS", b"OLEUM", b"OLIOS", b"OLLAS", b"OLOGY", b"OMBER", b"OMENS", b"OMERS", b"OMITS",
+    b"ONCET", b"ONIUM", b"ONTIC", b"OOHED", b"OOMPH", b"OORIE", b"OOTID", b"OOZED", b"OOZES",
+    b"OPAHS", b"OPALS", b"OPENS", b"OPING", b"OPSIN", b"OPTED", b"ORACH", b"ORALS", b"ORANG",
+    b"ORATE", b"ORBED", b"ORCAS", b"ORCIN", b"ORDOS", b"OREAD", b"ORGIC", b"ORIBI", b"ORIEL",
+    b"ORLES", b"ORLOP", b"ORMER", b"ORNIS", b"ORPIN", b"ORRIS", b"ORTHO", b"ORZOS", b"OSIER",
+    b"OSMIC", b"OSMOL", b"OSSIA", b"OSTIA", b"OTTAR", b"OTTOS", b"OUPHE", b"OUPHS", b"OURIE",
+    b"OUSEL", b"OUSTS", b"OUTBY", b"OUTED", b"OUTRE", b"OUZEL", b"OUZOS", b"OVALS", b"OVENS",
+    b"OVERS", b"OVOLI", b"OVOLO", b"OVULE", b"OWIES", b"OWLET", b"OWNED", b"OWSEN", b"OXBOW",
+    b"OXEYE", b"OXIDS", b"OXIME", b"OXIMS", b"OXLIP", b"OXTER", b"OYERS", b"PACAS", b"PACED",
+    b"PACER", b"PACES", b"PACHA", b"PACKS", b"PACTS", b"PADIS", b"PADLE", b"PADRE", b"PADRI",
+    b"PAEAN", b"PAEON", b"PAGED", b"PAGER", b"PAGES", b"PAGOD", b"PAIKS", b"PAILS", b"PAINS",
+    b"PAIRS", b"PAISA", b"PAISE", b"PALEA", b"PALED", b"PALES", b"PALET", b"PALLS", b"PALLY",
+    b"PALMS", b"PALMY", b"PALPI", b"PALPS", b"PAMPA", b"PANDA", b"PANDY", b"PANED", b"PANES",
+    b"PANGA", b"PANGS", b"PANNE", b"PANTO", b"PANTS", b"PANTY", b"PAPAS", b"PAPAW", b"PAPPI",
+    b"PAPPY", b"PARAE", b"PARAS", b"PARCH", b"PARDI", b"PARDS", b"PARDY", b"PARED", b"PAREO",
+    b"PARES", b"PAREU", b"PARGE", b"PARGO", b"PARIS", b"PARKS", b"PARLE", b"PAROL", b"PARRS",
+    b"PARTS", b"PARVE", b"PARVO", b"PASEO", b"PASES", b"PASHA", b"PASSE", b"PASTS", b"PATED",
+    b"PATEN", b"PATER", b"PATES", b"PATHS", b"PATIN", b"PATLY", b"PAVAN", b"PAVED", b"PAVER",
+    b"PAVES", b"PAVID", b"PAVIN", b"PAVIS", b"PAWED", b"PAWER", b"PAWKY", b"PAWLS", b"PAWNS",
+    b"PAXES", b"PAYED", b"PAYOR", b"PEAGE", b"PEAGS", b"PEAKS", b"PEAKY", b"PEALS", b"PEANS",
+    b"PEARS", b"PEART", b"PEASE", b"PEATS", b"PEATY", b"PEAVY", b"PECHS", b"PECKS", b"PECKY",
+    b"PEDES", b"PEDRO", b"PEEKS", b"PEELS", b"PEENS", b"PEEPS", b"PEERS", b"PEERY", b"PEEVE",
+    b"PEINS", b"PEISE", b"PEKAN", b"PEKES", b"PEKIN", b"PEKOE", b"PELES", b"PELFS", b"PELON",
+    b"PELTS", b"PENDS", b"PENES", b"PENGO", b"PENNA", b"PENNI", b"PEONS", b"PEONY", b"PEPLA",
+    b"PEPOS", b"PEPPY", b"PERDU", b"PERDY", b"PEREA", b"PERIS", b"PERKS", b"PERMS", b"PERPS",
+    b"PERRY", b"PERSE", b"PESOS", b"PESTS", b"PESTY", b"PETER", b"PETIT", b"PETTI", b"PETTO",
+    b"PEWEE", b"PEWIT", b"PHAGE", b"PHIAL", b"PHLOX", b"PHONO", b"PHYLA", b"PHYLE", b"PIANS",
+    b"PIBAL", b"PICAL", b"PICAS", b"PICKS", b"PICOT", b"PICUL", b"PIERS", b"PIETA", b"PIGMY",
+    b"PIKAS", b"PIKED", b"PIKER", b"PIKES", b"PIKIS", b"PILAF", b"PILAR", b"PILAU", b"PILAW",
+    b"PILEA", b"PILED", b"PILEI", b"PILES", b"PILIS", b"PILLS", b"PILUS", b"PIMAS", b"PIMPS",
+    b"PINAS", b"PINED", b"PINES", b"PINGO", b"PINGS", b"PINKO", b"PINKS", b"PINNA", b"PINNY",
+    b"PINON", b"PINOT", b"PINTA", b"PINTS", b"PINUP", b"PIONS", b"PIOUS", b"PIPAL", b"PIPED",
+    b"PIPES", b"PIPET", b"PIPIT", b"PIRNS", b"PIROG", b"PISCO", b"PISOS", b"PISTE", b"PITAS",
+    b"PITHS", b"PITON", b"PITTA", b"PIXES", b"PLACK", b"PLAGE", b"PLANS", b"PLASH", b"PLASM",
+    b"PLATS", b"PLATY", b"PLAYA", b"PLAYS", b"PLEAS", b"PLEBE", b"PLEBS", b"PLENA", b"PLEWS",
+    b"PLICA", b"PLIES", b"PLINK", b"PLODS", b"PLONK", b"PLOPS", b"PLOTS", b"PLOTZ", b"PLOWS",
+    b"PLOYS", b"PLUGS", b"PLUMS", b"PLUMY", b"PLYER", b"POACH", b"POCKS", b"POCKY", b"PODGY",
+    b"PODIA", b"POEMS", b"POETS", b"POGEY", b"POILU", b"POIND", b"POKED", b"POKES", b"POKEY",
+    b"POLED", b"POLER", b"POLES", b"POLIO", b"POLIS", b"POLLS", b"POLOS", b"POLYS", b"POMES",
+    b"POMMY", b"POMPS", b"PONCE", b"PONDS", b"PONES", b"PONGS", b"POODS", b"POOFS", b"POOFY",
+    b"POOHS", b"POOLS", b"POONS", b"POOPS", b"POORI", b"POPES", b"POPPA", b"POPSY", b"PORED",
+    b"PORES", b"PORGY", b"PORKY", b"PORNO", b"PORNS", b"PORNY", b"PORTS", b"POSED", b"POSES",
+    b"POSTS", b"POTSY", b"POTTO", b"POTTY", b"POUFS", b"POULT", b"POURS", b"POUTS", b"POXED",
+    b"POXES", b"PRAAM", b"PRAHU", b"PRAMS", b"PRANA", b"PRANG", b"PRAOS", b"PRASE", b"PRATE",
+    b"PRATS", b"PRAUS", b"PRAYS", b"PREED", b"PREES", b"PREOP", b"PREPS", b"PRESA", b"PRESE",
+    b"PREST", b"PREXY", b"PREYS", b"PRICY", b"PRIER", b"PRIES", b"PRIGS", b"PRILL", b"PRIMA",
+    b"PRIMI", b"PRIMP", b"PRIMS", b"PRINK", b"PRION", b"PRISE", b"PRISS", b"PROAS", b"PRODS",
+    b"PROEM", b"PROFS", b"PROGS", b"PROLE", b"PROMO", b"PROMS", b"PROPS", b"PROSO", b"PROSS",
+    b"PROST", b"PROSY", b"PROWS", b"PRUTA", b"PRYER", b"PSEUD", b"PSHAW", b"PSOAE", b"PSOAI",
+    b"PSOAS", b"PSYCH", b"PUBES", b"PUBIS", b"PUCES", b"PUCKA", b"PUCKS", b"PUDIC", b"PUFFS",
+    b"PUGGY", b"PUJAH", b"PUJAS", b"PUKED", b"PUKES", b"PUKKA", b"PULED", b"PULER", b"PULES",
+    b"PULIK", b"PULIS", b"PULLS", b"PULPS", b"PUMAS", b"PUMPS", b"PUNAS", b"PUNGS", b"PUNKA",
+    b"PUNKS", b"PUNKY", b"PUNNY", b"PUNTO", b"PUNTS", b"PUNTY", b"PUPAE", b"PUPAS", b"PURDA",
+    b"PURIN", b"PURIS", b"PURLS", b"PURRS", b"PURSY", b"PUSES", b"PUSSY", b"PUTON", b"PUTTI",
+    b"PUTTO", b"PUTTS", b"PYINS", b"PYLON", b"PYRAN", b"PYRES", b"PYRIC", b"PYXES", b"PYXIE",
+    b"PYXIS", b"QAIDS", b"QANAT", b"QOPHS", b"QUADS", b"QUAFF", b"QUAGS", b"QUAIR", b"QUAKY",
+    b"QUALE", b"QUANT", b"QUARE", b"QUASS", b"QUATE", b"QUAYS", b"QUBIT", b"QUEAN", b"QUERN",
+    b"QUEYS", b"QUIDS", b"QUIFF", b"QUINS", b"QUINT", b"QUIPS", b"QUIPU", b"QUIRE", b"QUIRT",
+    b"QUITS", b"QUODS", b"QUOIN", b"QUOIT", b"QURSH", b"RABIC", b"RACED", b"RACES", b"RACKS",
+    b"RACON", b"RADIX", b"RADON", b"RAFFS", b"RAFTS", b"RAGAS", b"RAGED", b"RAGEE", b"RAGES",
+    b"RAGGS", b"RAGGY", b"RAIDS", b"RAILS", b"RAINS", b"RAJAS", b"RAJES", b"RAKED", b"RAKEE",
+    b"RAKER", b"RAKES", b"RAKIS", b"RALES", b"RAMAL", b"RAMEE", b"RAMET", b"RAMIE", b"RAMMY",
+    b"RAMPS", b"RAMUS", b"RANCE", b"RANDS", b"RANEE", b"RANGY", b"RANID", b"RANIS", b"RANKS",
+    b"RANTS", b"RAPED", b"RAPER", b"RAPES", b"RAPHE", b"RARED", b"RARES", b"RASED", b"RASER",
+    b"RASES", b"RASPS", b"RATAL", b"RATAN", b"RATCH", b"RATED", b"RATEL", b"RATER", b"RATES",
+    b"RATHE", b"RATHS", b"RATOS", b"RAVED", b"RAVEL", b"RAVER", b"RAVES", b"RAVIN", b"RAWER",
+    b"RAWIN", b"RAWLY", b"RAXED", b"RAXES", b"RAYAH", b"RAYAS", b"RAYED", b"RAZED", b"RAZEE",
+    b"RAZER", b"RAZES", b"READD", b"READS", b"REALS", b"REAMS", b"REAPS", b"REARS", b"REATA",
+    b"REAVE", b"REBBE", b"REBEC", b"REBID", b"REBOP", b"REBUY", b"RECIT", b"RECKS", b"RECON",
+    b"RECTA", b"RECTI", b"RECTO", b"REDAN", b"REDDS", b"REDDY", b"REDED", b"REDES", b"REDIA",
+    b"REDID", b"REDIP", b"REDLY", b"REDON", b"REDOS", b"REDOX", b"REDRY", b"REDUB", b"REDUX",
+    b"REDYE", b"REEDS", b"REEFS", b"REEFY", b"REEKS", b"REEKY", b"REELS", b"REEST", b"REEVE",
+    b"REFED", b"REFEL", b"REFIX", b"REFLY", b"REGES", b"REGMA", b"REGNA", b"REHEM", b"REIFS",
+    b"REIFY", b"REINK", b"REINS", b"REIVE", b"REKEY", b"RELET", b"RELIT", b"REMAN", b"REMAP",
+    b"REMET", b"REMEX", b"REMIX", b"RENDS", b"RENIG", b"RENIN", b"RENTE", b"RENTS", b"REOIL",
+    b"REPEG", b"REPIN", b"REPOS", b"REPOT", b"REPPS", b"REPRO", b"RERAN", b"RERIG", b"RESAW",
+    b"RESAY", b"RESEE", b"RESEW", b"RESID", b"RESOD", b"RESOW", b"RESTS", b"RETAG", b"RETAX",
+    b"RETEM", b"RETIA", b"RETIE", b"REVET", b"REWAN", b"REWAX", b"REWED", b"REWET", b"REWIN",
+    b"REWON", b"REXES", b"RHEAS", b"RHEUM", b"RHOMB", b"RHUMB", b"RHYTA", b"RIALS", b"RIANT",
+    b"RIATA", b"RIBBY", b"RIBES", b"RICED", b"RICER", b"RICES", b"RICIN", b"RICKS", b"RIDES",
+    b"RIDGY", b"RIELS", b"RIFER", b"RIFFS", b"RIFTS", b"RILED", b"RILES", b"RILEY", b"RILLE",
+    b"RILLS", b"RIMED", b"RIMER", b"RIMES", b"RINDS", b"RINDY", b"RINGS", b"RINKS", b"RIOJA",
+    b"RIOTS", b"RIPED", b"RIPES", b"RISES", b"RISHI", b"RISKS", b"RISUS", b"RITES", b"RITZY",
+    b"RIVED", b"RIVEN", b"RIVES", b"RIYAL", b"ROADS", b"ROAMS", b"ROANS", b"ROARS", b"ROATE",
+    b"ROBED", b"ROBES", b"ROBLE", b"ROCKS", b"ROILS", b"ROILY", b"ROLES", b"ROLFS", b"ROLLS",
+    b"ROMAN", b"ROMEO", b"ROMPS", b"RONDO", b"ROODS", b"ROOFS", b"ROOKS", b"ROOKY", b"ROOMS",
+    b"ROOSE", b"ROOTS", b"ROOTY", b"ROPED", b"ROPER", b"ROPES", b"ROPEY", b"ROQUE", b"ROSED",
+    b"ROSES", b"ROSET", b"ROSIN", b"ROTAS", b"ROTCH", b"ROTES", b"ROTIS", b"ROTLS", b"ROTOS",
+    b"ROTTE", b"ROUEN", b"ROUES", b"ROUPS", b"ROUPY", b"ROUST", b"ROUTH", b"ROUTS", b"ROVED",
+    b"ROVEN", b"ROVES", b"ROWAN", b"ROWED", b"ROWEL", b"ROWEN", b"ROWTH", b"RUANA", b"RUBES",
+    b"RUBLE", b"RUBUS", b"RUCHE", b"RUCKS", b"RUDDS", b"RUERS", b"RUFFE", b"RUFFS", b"RUGAE",
+    b"RUGAL", b"RUING", b"RUINS", b"RULED", b"RULES", b"RUMEN", b"RUMMY", b"RUMPS", b"RUNES",
+    b"RUNGS", b"RUNIC", b"RUNNY", b"RUNTS", b"RUNTY", b"RUSES", b"RUSHY", b"RUSKS", b"RUSTS",
+    b"RUTHS", b"RUTIN", b"RUTTY", b"RYKED", b"RYKES", b"RYNDS", b"RYOTS", b"SABED", b"SABER",
+    b"SABES", b"SABIN", b"SABIR", b"SABLE", b"SABOT", b"SABRA", b"SABRE", b"SACKS", b"SACRA",
+    b"SADDO", b"SADES", b"SADHE", b"SADHU", b"SADIS", b"SAFES", b"SAGAS", b"SAGER", b"SAGES",
+    b"SAGGY", b"SAGOS", b"SAGUM", b"SAHIB", b"SAICE", b"SAIDS", b"SAIGA", b"SAILS", b"SAINS",
+    b"SAITH", b"SAJOU", b"SAKER", b"SAKES", b"SAKIS", b"SALAL", b"SALEP", b"SALES", b"SALET",
+    b"SALIC", b"SALMI", b"SALOL", b"SALPA", b"SALPS", b"SALTS", b"SAMBA", b"SAMBO", b"SAMEK",
+    b"SAMPS", b"SANDS", b"SANED", b"SANES", b"SANGA", b"SANGH", b"SANTO", b"SAPID", b"SAPOR",
+    b"SARAN", b"SARDS", b"SAREE", b"SARGE", b"SARIN", b"SARIS", b"SARKS", b"SAROD", b"SAROS",
+    b"SASIN", b"SATAI", b"SATAY", b"SATED", b"SATEM", b"SATES", b"SAUCH", b"SAUGH", b"SAULS",
+    b"SAULT", b"SAURY", b"SAVED", b"SAVER", b"SAVES", b"SAVIN", b"SAWED", b"SAWER", b"SAXES",
+    b"SAYED", b"SAYER", b"SAYID", b"SAYST", b"SCABS", b"SCADS", b"SCAGS", b"SCALL", b"SCAMS",
+    b"SCANS", b"SCAPE", b"SCARP", b"SCARS", b"SCART", b"SCATS", b"SCATT", b"SCAUP", b"SCAUR",
+    b"SCENA", b"SCEND", b"SCHMO", b"SCHUL", b"SCHWA", b"SCOOT", b"SCOPS", b"SCOTS", b"SCOWS",
+    b"SCRAG", b"SCRIM", b"SCRIP", b"SCROD", b"SCUDO", b"SCUDS", b"SCUFF", b"SCULK", b"SCULL",
+    b"SCULP", b"SCUMS", b"SCUPS", b"SCURF", b"SCUTA", b"SCUTE", b"SEALS", b"SEAMS", b"SEAMY",
+    b"SEARS", b"SEATS", b"SEBUM", b"SECCO", b"SECTS", b"SEDER", b"SEDGE", b"SEDGY", b"SEDUM",
+    b"SEEDS", b"SEEKS", b"SEELS", b"SEELY", b"SEEMS", b"SEEPS", b"SEEPY", b"SEERS", b"SEGNI",
+    b"SEGNO", b"SEGOS", b"SEIFS", b"SEINE", b"SEISE", b"SEISM", b"SELAH", b"SELLE", b"SELLS",
+    b"SELVA", b"SEMES", b"SEMIS", b"SENDS", b"SENGI", b"SENNA", b"SENOR", b"SENSA", b"SENTE",
+    b"SENTI", b"SEPAL", b"SEPIC", b"SEPOY", b"SEPTA", b"SEPTS", b"SERAC", b"SERAI", b"SERAL",
+    b"SERED", b"SERER", b"SERES", b"SERFS", b"SERGE", b"SERIN", b"SEROW", b"SERVO", b"SETAE",
+    b"SETAL", b"SETON", b"SETTS", b"SEWAN", b"SEWAR", b"SEWED", b"SEXED", b"SEXES", b"SEXTO",
+    b"SEXTS", b"SHADS", b"SHAGS", b"SHAHS", b"SHAKO", b"SHALY", b"SHAMS", b"SHARN", b"SHAUL",
+    b"SHAWM", b"SHAWN", b"SHAWS", b"SHAYS", b"SHEAF", b"SHEAL", b"SHEAS", b"SHEDS", b"SHEND",
+    b"SHENT", b"SHEOL", b"SHERD", b"SHEWN", b"SHEWS", b"SHIEL", b"SHIER", b"SHIES", b"SHILL",
+    b"SHILY", b"SHIMS", b"SHINS", b"SHIPS", b"SHIRR", b"SHIST", b"SHITS", b"SHIVA", b"SHIVE",
+    b"SHIVS", b"SHLEP", b"SHOAT", b"SHOED", b"SHOER", b"SHOES", b"SHOGI", b"SHOGS", b"SHOJI",
+    b"SHOOL", b"SHOON", b"SHOOS", b"SHOPS", b"SHORL", b"SHOTE", b"SHOTS", b"SHOTT", b"SHOWS",
+    b"SHOYU", b"SHRED", b"SHTIK", b"SHULN", b"SHULS", b"SHUNS", b"SHUTE", b"SHUTS", b"SHYER",
+    b"SIALS", b"SIBBS", b"SIBYL", b"SICES", b"SICKO", b"SICKS", b"SIDED", b"SIDES", b"SIDLE",
+    b"SIEUR", b"SIFTS", b"SIGHS", b"SIGIL", b"SIGNS", b"SIKAS", b"SIKER", b"SIKES", b"SILDS",
+    b"SILEX", b"SILKS", b"SILLS", b"SILOS", b"SILTS", b"SILTY", b"SILVA", b"SIMAR", b"SIMAS",
+    b"SIMPS", b"SINES", b"SINGS", b"SINHS", b"SINKS", b"SINUS", b"SIPED", b"SIPES", b"SIRED",
+    b"SIREE", b"SIRES", b"SIRRA", b"SIRUP", b"SISAL", b"SISES", b"SITAR", b"SITED", b"SITES",
+    b"SITUS", b"SIXES", b"SIXMO", b"SIXTE", b"SIZED", b"SIZER", b"SIZES", b"SKALD", b"SKATS",
+    b"SKEAN", b"SKEED", b"SKEEN", b"SKEES", b"SKEET", b"SKEGS", b"SKEIN", b"SKELM", b"SKELP",
+    b"SKENE", b"SKEPS", b"SKEWS", b"SKIDS", b"SKIED", b"SKIES", b"SKIEY", b"SKIMO", b"SKIMS",
+    b"SKINK", b"SKINS", b"SKINT", b"SKIPS", b"SKIRL", b"SKIRR", b"SKITE", b"SKITS", b"SKIVE",
+    b"SKOAL", b"SKOSH", b"SKUAS", b"SKYED", b"SKYEY", b"SLABS", b"SLAGS", b"SLAKE", b"SLAMS",
+    b"SLANK", b"SLAPS", b"SLATS", b"SLATY", b"SLAWS", b"SLAYS", b"SLEDS", b"SLEWS", b"SLIER",
+    b"SLILY", b"SLIMS", b"SLIPS", b"SLIPT", b"SLITS", b"SLOBS", b"SLOES", b"SLOGS", b"SLOJD",
+    b"SLOPS", b"SLOTS", b"SLOWS", b"SLOYD", b"SLUBS", b"SLUED", b"SLUES", b"SLUFF", b"SLUGS",
+    b"SLUMS", b"SLURB", b"SLURS", b"SLUTS", b"SLYER", b"SLYPE", b"SMALT", b"SMARM", b"SMAZE",
+    b"SMEEK", b"SMERK", b"SMOGS", b"SMOLT", b"SMUSH", b"SMUTS", b"SNAFU", b"SNAGS", b"SNAPS",
+    b"SNARF", b"SNARK", b"SNASH", b"SNATH", b"SNAWS", b"SNEAP", b"SNECK", b"SNEDS", b"SNELL",
+    b"SNIBS", b"SNICK", b"SNIPS", b"SNITS", b"SNOBS", b"SNOGS", b"SNOOD", b"SNOOK", b"SNOOL",
+    b"SNOOT", b"SNOTS", b"SNOWS", b"SNUBS", b"SNUGS", b"SNYES", b"SOAKS", b"SOAPS", b"SOARE",
+    b"SOARS", b"SOBAS", b"SOCAS", b"SOCKO", b"SOCKS", b"SOCLE", b"SODAS", b"SODDY", b"SODIC",
+    b"SODOM", b"SOFAR", b"SOFAS", b"SOFTA", b"SOFTS", b"SOFTY", b"SOILS", b"SOJAS", b"SOKES",
+    b"SOKOL", b"SOLAN", b"SOLDI", b"SOLDO", b"SOLED", b"SOLEI", b"SOLES", b"SOLON", b"SOLOS",
+    b"SOLUM", b"SOLUS", b"SOMAN", b"SOMAS", b"SONDE", b"SONES", b"SONGS", b"SONLY", b"SONNY",
+    b"SONSY", b"SOOEY", b"SOOKS", b"SOOTS", b"SOPHS", b"SOPHY", b"SOPOR", b"SOPPY", b"SORAS",
+    b"SORBS", b"SORDS", b"SORED", b"SOREL", b"SORER", b"SORES", b"SORGO", b"SORNS", b"SORTS",
+    b"SORUS", b"SOTHS", b"SOTOL", b"SOUGH", b"SOUKS", b"SOULS", b"SOUPS", b"SOUPY", b"SOURS",
+    b"SOUSE", b"SOWAR", b"SOWED", b"SOYAS", b"SOYUZ", b"SOZIN", b"SPACY", b"SPADO", b"SPAED",
+    b"SPAES", b"SPAHI", b"SPAIL", b"SPAIT", b"SPAKE", b"SPALE", b"SPALL", b"SPANG", b"SPANS",
+    b"SPARS", b"SPATE", b"SPATS", b"SPAYS", b"SPAZZ", b"SPEAN", b"SPECS", b"SPEEL", b"SPEER",
+    b"SPEIL", b"SPEIR", b"SPEWS", b"SPICA", b"SPICK", b"SPICS", b"SPIER", b"SPIES", b"SPIFF",
+    b"SPIKS", b"SPILE", b"SPINS", b"SPIRT", b"SPIRY", b"SPITS", b"SPITZ", b"SPIVS", b"SPLAY",
+    b"SPODE", b"SPOOR", b"SPOTS", b"SPRAG", b"SPRAT", b"SPRIT", b"SPROG", b"SPRUE", b"SPUDS",
+    b"SPUED", b"SPUES", b"SPUME", b"SPUMY", b"SPURS", b"SPUTA", b"SQUAB", b"SQUAW", b"SQUEG",
+    b"SQUID", b"STABS", b"STADE", b"STAGS", b"STAGY", b"STANE", b"STANG", b"STAPH", b"STARS",
+    b"STATS", b"STAYS", b"STEEK", b"STELA", b"STELE", b"STEMS", b"STENO", b"STENT", b"STEPS",
+    b"STERE", b"STETS", b"STEWS", b"STICH", b"STIED", b"STIES", b"STILE", b"STIME", b"STIMY",
+    b"STIPE", b"STIRK", b"STIRP", b"STIRS", b"STOAE", b"STOAI", b"STOAS", b"STOAT", b"STOBS",
+    b"STOGY", b"STOMA", b"STOOK", b"STOPE", b"STOPS", b"STOPT", b"STOSS", b"STOUP", b"STOUR",
+    b"STOWP", b"STOWS", b"STREP", b"STREW", b"STRIA", b"STROP", b"STROW", b"STROY", b"STRUM",
+    b"STUBS", b"STUDS", b"STULL", b"STUMS", b"STUNS", b"STUPA", b"STUPE", b"STURT", b"STYLI",
+    b"STYMY", b"SUBAH", b"SUBAS", b"SUBER", b"SUCKS", b"SUCRE", b"SUDDS", b"SUDOR", b"SUDSY",
+    b"SUEDE", b"SUERS", b"SUETS", b"SUETY", b"SUINT", b"SUITS", b"SULCI", b"SULFA", b"SULFO",
+    b"SULKS", b"SULUS", b"SUMMA", b"SUMOS", b"SUMPS", b"SUNNA", b"SUNNS", b"SUNUP", b"SUPES",
+    b"SUPRA", b"SURAH", b"SURAL", b"SURAS", b"SURDS", b"SURFS", b"SURFY", b"SURGY", b"SUTRA",
+    b"SUTTA", b"SWABS", b"SWAGE", b"SWAGS", b"SWAIL", b"SWAIN", b"SWALE", b"SWAMY", b"SWANG",
+    b"SWANK", b"SWANS", b"SWAPS", b"SWARD", b"SWARE", b"SWARF", b"SWART", b"SWATS", b"SWAYS",
+    b"SWEDE", b"SWEER", b"SWIGS", b"SWIMS", b"SWINK", b"SWIPE", b"SWISS", b"SWITH", b"SWIVE",
+    b"SWOBS", b"SWOPS", b"SWOTS", b"SWOUN", b"SYCEE", b"SYCES", b"SYKES", b"SYLIS", b"SYLPH",
+    b"SYLVA", b"SYNCH", b"SYNCS", b"SYNTH", b"SYRAH", b"SYREN", b"SYSOP", b"TABER", b"TABES",
+    b"TABID", b"TABLA", b"TABOR", b"TABUN", b"TABUS", b"TACES", b"TACET", b"TACHE", b"TACHS",
+    b"TACKS", b"TACOS", b"TACTS", b"TAELS", b"TAFIA", b"TAHRS", b"TAIGA", b"TAILS", b"TAINS",
+    b"TAJES", b"TAKAS", b"TAKES", b"TAKIN", b"TALAR", b"TALAS", b"TALCS", b"TALEA", b"TALER",
+    b"TALES", b"TALKS", b"TALKY", b"TALUK", b"TALUS", b"TAMAL", b"TAMED", b"TAMES", b"TAMIS",
+    b"TAMMY", b"TAMPS", b"TANGS", b"TANKA", b"TANKS", b"TANSY", b"TANTO", b"TAPAS", b"TAPED",
+    b"TAPES", b"TAPIS", b"TARDO", b"TARED", b"TARES", b"TARGE", b"TARNS", b"TAROC", b"TAROK",
+    b"TAROS", b"TARPS", b"TARRE", b"TARRY", b"TARSI", b"TARTS", b"TARTY", b"TASKS", b"TASSE",
+    b"TATAR", b"TATER", b"TATES", b"TAUON", b"TAUPE", b"TAUTS", b"TAWED", b"TAWER", b"TAWIE",
+    b"TAWSE", b"TAXED", b"TAXER", b"TAXES", b"TAXIS", b"TAXON", b"TAXUS", b"TAZZA", b"TAZZE",
+    b"TEAKS", b"TEALS", b"TEAMS", b"TEARS", b"TEATS", b"TECHY", b"TECTA", b"TEELS", b"TEEMS",
+    b"TEENS", b"TEENY", b"TEGUA", b"TEIID", b"TEILS", b"TELAE", b"TELCO", b"TELES", b"TELEX",
+    b"TELIA", b"TELIC", b"TELLS", b"TELLY", b"TELOI", b"TELOS", b"TEMPI", b"TEMPS", b"TEMPT",
+    b"TENCH", b"TENDS", b"TENIA", b"TENON", b"TENTS", b"TENTY", b"TEPAL", b"TEPAS", b"TEPOY",
+    b"TERAI", b"TERCE", b"TERGA", b"TERMS", b"TERNE", b"TERNS", b"TERRY", b"TESLA", b"TESTA",
+    b"TESTS", b"TETHS", b"TETRA", b"TEUCH", b"TEUGH", b"TEXAS", b"TEXTS", b"THANE", b"THARM",
+    b"THAWS", b"THEBE", b"THECA", b"THEGN", b"THEIN", b"THENS", b"THERM", b"THEWS", b"THEWY",
+    b"THILL", b"THINE", b"THINS", b"THIOL", b"THIRL", b"THOLE", b"THORO", b"THORP", b"THOUS",
+    b"THRAW", b"THRIP", b"THROE", b"THUDS", b"THUGS", b"THUJA", b"THUNK", b"THURL", b"THUYA",
+    b"THYMI", b"THYMY", b"TICAL", b"TICKS", b"TIDED", b"TIDES", b"TIERS", b"TIFFS", b"TIGON",
+    b"TIKES", b"TIKIS", b"TILAK", b"TILED", b"TILER", b"TILES", b"TILLS", b"TILTH", b"TILTS",
+    b"TIMED", b"TIMES", b"TINCT", b"TINEA", b"TINED", b"TINES", b"TINGE", b"TINGS", b"TINNY",
+    b"TINTS", b"TIPIS", b"TIPPY", b"TIRED", b"TIRES", b"TIRLS", b"TIROS", b"TITER", b"TITIS",
+    b"TITRE", b"TITTY", b"TIZZY", b"TOADS", b"TOADY", b"TOEAS", b"TOFFS", b"TOFFY", b"TOFTS",
+    b"TOFUS", b"TOGAE", b"TOGAS", b"TOGUE", b"TOILE", b"TOILS", b"TOITS", b"TOKAY", b"TOKED",
+    b"TOKER", b"TOKES", b"TOLAN", b"TOLAS", b"TOLED", b"TOLES", b"TOLLS", b"TOLUS", b"TOLYL",
+    b"TOMAN", b"TOMBS", b"TOMES", b"TOMMY", b"TONDI", b"TONDO", b"TONED", b"TONER", b"TONES",
+    b"TONEY", b"TONGS", b"TONNE", b"TONUS", b"TOOLS", b"TOONS", b"TOOTS", b"TOPED", b"TOPEE",
+    b"TOPER", b"TOPES", b"TOPHE", b"TOPHI", b"TOPHS", b"TOPIS", b"TOPOI", b"TOPOS", b"TOQUE",
+    b"TORAH", b"TORAS", b"TORCS", b"TORES", b"TORIC", b"TORII", b"TOROS", b"TOROT", b"TORSE",
+    b"TORSI", b"TORSK", b"TORTE", b"TORTS", b"TOTED", b"TOTER", b"TOTES", b"TOURS", b"TOUSE",
+    b"TOUTS", b"TOWED", b"TOWIE", b"TOWNS", b"TOYED", b"TOYER", b"TOYON", b"TRAGI", b"TRAIK",
+    b"TRAMS", b"TRANK", b"TRANQ", b"TRANS", b"TRAPS", b"TRAPT", b"TRASS", b"TRAVE", b"TRAYS",
+    b"TRECK", b"TREED", b"TREES", b"TREKS", b"TRESS", b"TREWS", b"TREYS", b"TRIER", b"TRIES",
+    b"TRIGO", b"TRIGS", b"TRIKE", b"TRILL", b"TRIMS", b"TRINE", b"TRIOL", b"TRIOS", b"TRIPS",
+    b"TROAK", b"TROCK", b"TRODE", b"TROIS", b"TROKE", b"TROMP", b"TRONA", b"TRONE", b"TROOZ",
+    b"TROTH", b"TROTS", b"TROWS", b"TRUED", b"TRUES", b"TRUGS", b"TRULL", b"TRYMA", b"TSADE",
+    b"TSADI", b"TSARS", b"TSKED", b"TSUBA", b"TUBAE", b"TUBAS", b"TUBBY", b"TUBED", b"TUBES",
+    b"TUCKS", b"TUFAS", b"TUFFS", b"TUFTS", b"TUFTY", b"TULES", b"TUMID", b"TUMMY", b"TUMPS",
+    b"TUNAS", b"TUNED", b"TUNER", b"TUNES", b"TUNGS", b"TUNNY", b"TUPIK", b"TUQUE", b"TURDS",
+    b"TURFS", b"TURFY", b"TURKS", b"TURNS", b"TURPS", b"TUSHY", b"TUSKS", b"TUTEE", b"TUTTI",
+    b"TUTTY", b"TUTUS", b"TUXES", b"TWAIN", b"TWATS", b"TWEEN", b"TWERP", b"TWIER", b"TWIGS",
+    b"TWILL", b"TWINS", b"TWINY", b"TWIRP", b"TWITS", b"TWYER", b"TYEES", b"TYERS", b"TYKES",
+    b"TYMPS", b"TYNED", b"TYNES", b"TYPAL", b"TYPED", b"TYPES", b"TYPEY", b"TYPIC", b"TYPOS",
+    b"TYPPS", b"TYRED", b"TYRES", b"TYROS", b"TYTHE", b"TZARS", b"UHLAN", b"UKASE", b"ULAMA",
+    b"ULANS", b"ULEMA", b"ULNAD", b"ULNAE", b"ULNAR", b"ULNAS", b"ULPAN", b"ULVAS", b"UMBEL",
+    b"UMBER", b"UMBOS", b"UMIAC", b"UMIAK", b"UMIAQ", b"UMPED", b"UNAIS", b"UNAPT", b"UNARM",
+    b"UNARY", b"UNAUS", b"UNBAN", b"UNBAR", b"UNBOX", b"UNCAP", b"UNCIA", b"UNCOS", b"UNCOY",
+    b"UNDEE", b"UNFIX", b"UNHAT", b"UNHIP", b"UNITS", b"UNJAM", b"UNLAY", b"UNLED", b"UNLET",
+    b"UNMAN", b"UNMEW", b"UNMIX", b"UNPEG", b"UNPEN", b"UNPIN", b"UNRIG", b"UNRIP", b"UNSAY",
+    b"UNSEW", b"UNSEX", b"UNWIT", b"UNWON", b"UPBOW", b"UPBYE", b"UPDOS", b"UPDRY", b"UPEND",
+    b"UPLIT", b"UPPED", b"URAEI", b"URASE", b"URATE", b"URBIA", b"UREAL", b"UREAS", b"UREDO",
+    b"UREIC", b"URGED", b"URGER", b"URGES", b"URIAL", b"URSAE", b"USERS", b"USNEA", b"USQUE",
+    b"USURY", b"UTERI", b"UVEAL", b"UVEAS", b"UVULA", b"VACUA", b"VAGAL", b"VAGUS", b"VAILS",
+    b"VAIRS", b"VAKIL", b"VALES", b"VALSE", b"VAMPS", b"VANDA", b"VANED", b"VANES", b"VANGS",
+    b"VARAS", b"VARIA", b"VARIX", b"VARNA", b"VARUS", b"VARVE", b"VASAL", b"VASES", b"VASTS",
+    b"VASTY", b"VATIC", b"VATUS", b"VEALS", b"VEALY", b"VEENA", b"VEEPS", b"VEERS", b"VEERY",
+    b"VEGIE", b"VEILS", b"VEINS", b"VEINY", b"VELAR", b"VELDS", b"VELDT", b"VELUM", b"VENAE",
+    b"VENAL", b"VENDS", b"VENGE", b"VENIN", b"VENTS", b"VERBS", b"VERST", b"VERTS", b"VERTU",
+    b"VESTA", b"VESTS", b"VETCH", b"VEXED", b"VEXER", b"VEXES", b"VEXIL", b"VIALS", b"VIAND",
+    b"VIBES", b"VICED", b"VICES", b"VICHY", b"VIERS", b"VIEWS", b"VIEWY", b"VIGAS", b"VILER",
+    b"VILLI", b"VILLS", b"VIMEN", b"VINAL", b"VINAS", b"VINCA", b"VINED", b"VINES", b"VINIC",
+    b"VINOS", b"VIOLS", b"VIREO", b"VIRES", b"VIRGA", b"VIRID", b"VIRLS", b"VIRTU", b"VISAS",
+    b"VISED", b"VISES", b"VITAE", b"VITTA", b"VIVAS", b"VIZIR", b"VIZOR", b"VOCES", b"VODOU",
+    b"VODUN", b"VOGIE", b"VOIDS", b"VOILE", b"VOLAR", b"VOLED", b"VOLES", b"VOLTA", b"VOLTE",
+    b"VOLTI", b"VOLTS", b"VOLVA", b"VOMER", b"VOTED", b"VOTES", b"VOWED", b"VOWER", b"VROOM",
+    b"VROUW", b"VROWS", b"VUGGS", b"VUGGY", b"VUGHS", b"VULGO", b"VULVA", b"WACKE", b"WACKO",
+    b"WACKS", b"WADDY", b"WADED", b"WADER", b"WADES", b"WADIS", b"WAFFS", b"WAFTS", b"WAGED",
+    b"WAGES", b"WAHOO", b"WAIFS", b"WAILS", b"WAINS", b"WAIRS", b"WAITE", b"WAITS", b"WAKED",
+    b"WAKEN", b"WAKER", b"WAKES", b"WALED", b"WALER", b"WALES", b"WALKS", b"WALLA", b"WALLS",
+    b"WALLY", b"WAMES", b"WAMUS", b"WANDS", b"WANED", b"WANES", b"WANEY", b"WANLY", b"WANTS",
+    b"WARDS", b"WARED", b"WARES", b"WARKS", b"WARMS", b"WARNS", b"WARPS", b"WARTS", b"WASHY",
+    b"WASPS", b"WASPY", b"WASTS", b"WATAP", b"WATTS", b"WAUGH", b"WAUKS", b"WAULS", b"WAVED",
+    b"WAVES", b"WAVEY", b"WAWLS", b"WAXED", b"WAXER", b"WAXES", b"WEALD", b"WEALS", b"WEANS",
+    b"WEARS", b"WEBBY", b"WEBER", b"WECHT", b"WEDEL", b"WEDGY", b"WEEDS", b"WEEKS", b"WEENS",
+    b"WEENY", b"WEEPS", b"WEEPY", b"WEEST", b"WEETS", b"WEFTS", b"WEIRS", b"WEKAS", b"WELDS",
+    b"WELLS", b"WELTS", b"WENDS", b"WENNY", b"WESTS", b"WETLY", b"WHAMS", b"WHANG", b"WHAPS",
+    b"WHATS", b"WHAUP", b"WHEAL", b"WHEEN", b"WHEEP", b"WHELK", b"WHELM", b"WHETS", b"WHEWS",
+    b"WHEYS", b"WHIDS", b"WHIGS", b"WHIMS", b"WHINS", b"WHIPS", b"WHIPT", b"WHIRR", b"WHIRS",
+    b"WHISH", b"WHIST", b"WHITS", b"WHITY", b"WHIZZ", b"WHOMP", b"WHOOF", b"WHOPS", b"WHORL",
+    b"WHORT", b"WHOSO", b"WHUMP", b"WICKS", b"WIDES", b"WIFED", b"WIFEY", b"WIFTY", b"WIGAN",
+    b"WIGGY", b"WILCO", b"WILDS", b"WILED", b"WILES", b"WILLS", b"WILTS", b"WIMPS", b"WINDS",
+    b"WINED", b"WINES", b"WINEY", b"WINGS", b"WINGY", b"WINKS", b"WINOS", b"WINZE", b"WIPED",
+    b"WIPER", b"WIPES", b"WIRED", b"WIRER", b"WIRES", b"WIRRA", b"WISED", b"WISES", b"WISHA",
+    b"WISPS", b"WISTS", b"WITAN", b"WITED", b"WITES", b"WITHE", b"WITHY", b"WIVED", b"WIVER",
+    b"WIVES", b"WIZEN", b"WOADS", b"WOALD", b"WODGE", b"WOFUL", b"WOLDS", b"WOLFS", b"WOMBS",
+    b"WOMBY", b"WONKS", b"WONKY", b"WONTS", b"WOODS", b"WOOED", b"WOOFS", b"WOOLS", b"WORDS",
+    b"WORKS", b"WORMS", b"WORMY", b"WORTS", b"WOWED", b"WRANG", b"WRAPS", b"WRAPT", b"WRENS",
+    b"WRICK", b"WRIED", b"WRIER", b"WRIES", b"WRITS", b"WROTH", b"WRYER", b"WURST", b"WUSSY",
+    b"WYLED", b"WYLES", b"WYNDS", b"WYNNS", b"WYTED", b"WYTES", b"XEBEC", b"XENIA", b"XENIC",
+    b"XENON", b"XERIC", b"XEROX", b"XERUS", b"XYLAN", b"XYLEM", b"XYSTI", b"XYSTS", b"YACKS",
+    b"YAFFS", b"YAGER", b"YAGIS", b"YAHOO", b"YAIRD", b"YAMEN", b"YAMUN", b"YANGS", b"YANKS",
+    b"YAPOK", b"YAPON", b"YARDS", b"YARER", b"YARNS", b"YAUDS", b"YAULD", b"YAUPS", b"YAWED",
+    b"YAWLS", b"YAWNS", b"YAWPS", b"YEANS", b"YEARS", b"YECCH", b"YECHS", b"YECHY", b"YELKS",
+    b"YELLS", b"YELPS", b"YENTA", b"YENTE", b"YERBA", b"YERKS", b"YESES", b"YETIS", b"YETTS",
+    b"YEUKS", b"YEUKY", b"YIKES", b"YILLS", b"YINCE", b"YIPES", b"YIRDS", b"YIRRS", b"YIRTH",
+    b"YLEMS", b"YOBBO", b"YOCKS", b"YODEL", b"YODHS", b"YODLE", b"YOGAS", b"YOGEE", b"YOGHS",
+    b"YOGIC", b"YOGIN", b"YOGIS", b"YOKED", b"YOKEL", b"YOKES", b"YOLKS", b"YOLKY", b"YONIC",
+    b"YONIS", b"YOWED", b"YOWES", b"YOWIE", b"YOWLS", b"YUANS", b"YUCAS", b"YUCCA", b"YUCCH",
+    b"YUCKS", b"YUCKY", b"YUGAS", b"YUKKY", b"YULAN", b"YULES", b"YUMMY", b"YUPON", b"YURTA",
+    b"YURTS", b"ZAIRE", b"ZAMIA", b"ZANZA", b"ZAPPY", b"ZARFS", b"ZAXES", b"ZAYIN", b"ZAZEN",
+    b"ZEALS", b"ZEBEC", b"ZEBUS", b"ZEINS", b"ZERKS", b"ZEROS", b"ZESTS", b"ZETAS", b"ZIBET",
+    b"ZILCH", b"ZILLS", b"ZINCO", b"ZINCS", b"ZINCY", b"ZINEB", b"ZINGS", b"ZINGY", b"ZINKE",
+    b"ZIPPY", b"ZIRAM", b"ZITIS", b"ZIZIT", b"ZLOTE", b"ZLOTY", b"ZOEAE", b"ZOEAL", b"ZOEAS",
+    b"ZOMBI", b"ZONAE", b"ZONED", b"ZONER", b"ZONES", b"ZONKS", b"ZOOID", b"ZOOKS", b"ZOOMS",
+    b"ZOONS", b"ZOOTY", b"ZORIL", b"ZORIS", b"ZOUKS", b"ZOWIE", b"ZYMES",
+];
+
+static WORD_SET: OnceLock<WordSet> = OnceLock::new();
+static GUESS_SET: OnceLock<WordSet> = OnceLock::new();
+
+type WordSet = HashSet<&'static Word, WordHashBuilder>;
+
+fn init_word_set(words: &'static [&'static Word]) -> WordSet {
+    let mut word_set = HashSet::with_capacity_and_hasher(words.len(), WordHashBuilder);
+    for word in words {
+        word_set.insert(*word);
     }
     word_set
 }
 
-pub fn check(word: &Word) -> bool {
-    let word_set = WORD_SET.get_or_init(init_word_set);
+pub fn is_answer(word: &Word) -> bool {
+    let word_set = WORD_SET.get_or_init(|| init_word_set(&WORDS));
     word_set.contains(word)
 }
 
+pub fn check(word: &Word) -> bool {
+    let guess_set = GUESS_SET.get_or_init(|| init_word_set(&GUESSES));
+    is_answer(word) || guess_set.contains(word)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,6 +973,20 @@ mod tests {
     fn test_check() {
         for word in WORDS {
             assert!(check(word));
+            assert!(is_answer(word));
+        }
+        for word in GUESSES {
+            assert!(check(word));
+            assert!(!is_answer(word));
         }
+        assert!(check(b"AAHED"));
+        assert!(check(b"SOARE"));
+        assert!(!check(b"XXXXX"));
+    }
+
+    #[test]
+    fn test_sorted() {
+        assert!(WORDS.windows(2).all(|w| w[0] < w[1]));
+        assert!(GUESSES.windows(2).all(|w| w[0] < w[1]));
     }
 }
@@ -0,0 +1,47 @@
+//! Reading and writing the small files games, stats and caches are kept in.
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read};
+use std::path::Path;
+
+/// The most bytes [`read_capped`] reads, far more than any valid saved game or stats take up.
+pub const MAX_FILE_LEN: u64 = 64 * 1024;
+
+/// Reads `r` to a string, stopping after [`MAX_FILE_LEN`] bytes, so a corrupted file can't fill
+/// up memory. Anything longer is cut short, which the parser then rejects.
+pub fn read_capped(r: impl Read) -> io::Result<String> {
+    let mut buf = String::new();
+    r.take(MAX_FILE_LEN).read_to_string(&mut buf)?;
+    Ok(buf)
+}
+
+/// Opens the file at `path` and reads it with `read`. A missing file reads as `T::default()`,
+/// e.g. no games played.
+pub fn load_or_default<T: Default, E: From<io::Error>>(
+    path: &Path,
+    read: impl FnOnce(File) -> Result<T, E>,
+) -> Result<T, E> {
+    match File::open(path) {
+        Ok(file) => read(file),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Writes the file at `path` with `write`, creating its directory if needed.
+///
+/// The data is written to a temporary file next to it first, which then replaces `path` at once,
+/// so readers never see a partly written file and a write cut short loses nothing.
+pub fn write_atomic<E: From<io::Error>>(
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<(), E>,
+) -> Result<(), E> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    let mut tmp = BufWriter::new(File::create(&tmp_path)?);
+    write(&mut tmp)?;
+    tmp.into_inner().map_err(io::Error::from)?.sync_all()?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
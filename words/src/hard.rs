@@ -0,0 +1,146 @@
+//! Hard mode: every hint revealed so far must be used in later guesses.
+//!
+//! Letters scored [`Right`](LetterScore::Right) must stay in place, and every letter scored
+//! [`InWord`](LetterScore::InWord) or `Right` must appear in the guess again, as many times as it
+//! was revealed.
+use std::error::Error;
+use std::fmt;
+
+use crate::score::{LetterScore, Score};
+use crate::Word;
+
+/// Checks that `guess` uses all hints of the `previous` guesses and their scores.
+pub fn validate<const N: usize>(
+    guess: &Word<N>,
+    previous: &[(Word<N>, Score<N>)],
+) -> Result<(), Violation> {
+    for (word, score) in previous {
+        for (at, (&letter, s)) in word.iter().zip(*score).enumerate() {
+            if s == LetterScore::Right && guess[at] != letter {
+                return Err(Violation::Misplaced { at, letter });
+            }
+        }
+
+        for &letter in word {
+            let revealed = word
+                .iter()
+                .zip(*score)
+                .filter(|&(&l, s)| l == letter && s != LetterScore::Wrong)
+                .count();
+            let found = guess.iter().filter(|&&l| l == letter).count();
+            if found < revealed {
+                return Err(Violation::Missing {
+                    letter,
+                    count: revealed,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A hard mode rule broken by a guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Violation {
+    /// The letter at `at` (counting from 0) must be `letter`.
+    Misplaced { at: usize, letter: u8 },
+    /// The guess must contain `letter` at least `count` times.
+    Missing { letter: u8, count: usize },
+}
+
+impl Error for Violation {}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Misplaced { at, letter } => {
+                let n = at + 1;
+                let suffix = match n {
+                    1 => "st",
+                    2 => "nd",
+                    3 => "rd",
+                    _ => "th",
+                };
+                write!(f, "{n}{suffix} letter must be {}", letter as char)
+            }
+            Self::Missing { letter, count: 1 } => {
+                write!(f, "guess must contain {}", letter as char)
+            }
+            Self::Missing { letter, count } => {
+                write!(f, "guess must contain {count} {}s", letter as char)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score::score;
+
+    fn guessed<const N: usize>(word: &Word<N>, guesses: &[&Word<N>]) -> Vec<(Word<N>, Score<N>)> {
+        guesses.iter().map(|g| (**g, score(word, g))).collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        // ybgbg
+        let previous = guessed(b"CLOSE", &[b"STONE"]);
+        assert_eq!(validate(b"CLOSE", &previous), Ok(()));
+        assert_eq!(validate(b"PROSE", &previous), Ok(()));
+        assert_eq!(
+            validate(b"SLATE", &previous),
+            Err(Violation::Misplaced {
+                at: 2,
+                letter: b'O'
+            })
+        );
+        assert_eq!(
+            validate(b"PROVE", &previous),
+            Err(Violation::Missing {
+                letter: b'S',
+                count: 1
+            })
+        );
+
+        // letters revealed more than once must be used as often
+        let previous = guessed(b"EERIE", &[b"GEESE"]);
+        assert_eq!(validate(b"EERIE", &previous), Ok(()));
+        assert_eq!(
+            validate(b"HEAVE", &previous),
+            Err(Violation::Missing {
+                letter: b'E',
+                count: 3
+            })
+        );
+
+        // hints of every earlier guess count, not just the last one
+        let previous = guessed(b"CLOSE", &[b"CLAMP", b"STONE"]);
+        assert_eq!(validate(b"CLOSE", &previous), Ok(()));
+        assert_eq!(
+            validate(b"PROSE", &previous),
+            Err(Violation::Misplaced {
+                at: 0,
+                letter: b'C'
+            })
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let misplaced = |at| Violation::Misplaced { at, letter: b'R' }.to_string();
+        assert_eq!(misplaced(0), "1st letter must be R");
+        assert_eq!(misplaced(1), "2nd letter must be R");
+        assert_eq!(misplaced(2), "3rd letter must be R");
+        assert_eq!(misplaced(9), "10th letter must be R");
+        assert_eq!(
+            Violation::Missing {
+                letter: b'E',
+                count: 2
+            }
+            .to_string(),
+            "guess must contain 2 Es"
+        );
+    }
+}
@@ -1,14 +1,21 @@
 use super::Word;
 use std::hash::{BuildHasher, Hasher};
 
-pub fn perfect_hash(w: &Word) -> u64 {
+/// Returns a hash of `w` that is unique among all words of the same length made up of
+/// uppercase ASCII letters.
+pub fn perfect_hash<const N: usize>(w: &Word<N>) -> u64 {
+    perfect_hash_bytes(w)
+}
+
+fn perfect_hash_bytes(w: &[u8]) -> u64 {
+    debug_assert!(w.len() <= crate::MAX_LEN, "word is too long: {}", w.len());
+    let group_len = w.len();
     let mut hash = 0;
     for (i, byte) in w.iter().copied().enumerate() {
-        // set the ith bit of the nth six-bit group in hash to the nth bit of the ith byte.
+        // set the ith bit of the nth group in hash to the nth bit of the ith byte.
         for bit_idx in 0..5 {
-            let bit = ((byte & (1 << bit_idx)) >> bit_idx) << i;
-            let bit = bit as u64;
-            hash |= bit << (bit_idx * 6);
+            let bit = ((byte & (1 << bit_idx)) >> bit_idx) as u64;
+            hash |= bit << (i + bit_idx * group_len);
         }
     }
     // shift more entropy into the top 7 bits because swiss table uses those to short circuit
@@ -16,29 +23,31 @@ pub fn perfect_hash(w: &Word) -> u64 {
     hash << 2
 }
 
+/// A [`Hasher`] for words, see [`perfect_hash`].
 #[derive(Default)]
-pub struct WordHasher(Word);
+pub struct WordHasher(u64);
 
+#[derive(Clone, Copy, Debug, Default)]
 pub struct WordHashBuilder;
 
 impl Hasher for WordHasher {
     fn finish(&self) -> u64 {
-        perfect_hash(&self.0)
+        self.0
     }
 
     fn write(&mut self, bytes: &[u8]) {
-        // `[T; _]` will write its len as part of its `hash` impl
-        // don't need to do that, because all our data has len 5.
-        if bytes.len() == std::mem::size_of::<usize>() {
-            return;
-        }
         // assert `bytes` are a `Word`
         debug_assert!(
             bytes.is_ascii(),
             "word is not ascii, non ascii byte: {}",
             bytes.iter().find(|b| !b.is_ascii()).unwrap()
         );
-        self.0 = bytes.try_into().expect("word does not have length 5");
+        self.0 = perfect_hash_bytes(bytes);
+    }
+
+    fn write_usize(&mut self, _: usize) {
+        // `[T; _]` will write its len as part of its `hash` impl
+        // don't need to do that, because all words in a table have the same length.
     }
 }
 
@@ -54,14 +63,14 @@ impl BuildHasher for WordHashBuilder {
 mod tests {
     use std::collections::{HashMap, HashSet};
 
-    use crate::data::WORDS;
+    use crate::data::{GUESSES, WORDS};
 
     use super::*;
 
     #[test]
     fn test_is_perfect() {
-        let mut hashes = HashSet::with_capacity(WORDS.len());
-        for word in WORDS {
+        let mut hashes = HashSet::with_capacity(WORDS.len() + GUESSES.len());
+        for word in WORDS.iter().chain(&GUESSES) {
             let hash = perfect_hash(word);
             assert!(hashes.replace(hash).is_none());
         }
@@ -84,4 +93,20 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_is_perfect_other_lengths() {
+        let mut hashes = HashSet::new();
+        for a in b'A'..=b'Z' {
+            for b in b'A'..=b'Z' {
+                for c in b'A'..=b'Z' {
+                    assert!(hashes.insert(perfect_hash(&[a, b, c])));
+                }
+            }
+        }
+
+        let longest = *b"ZZZZZZZZZZ";
+        assert_ne!(perfect_hash(&longest), perfect_hash(b"ZZZZZZZZZY"));
+        assert!(perfect_hash(&longest) < 1 << 52);
+    }
 }
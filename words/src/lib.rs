@@ -1,22 +1,51 @@
+pub use daily::{daily_word, Date};
 pub use data::Word;
+pub use data::GUESSES;
 pub use data::WORDS;
+pub use list::WordList;
 
 use rand::{seq::SliceRandom, Rng, RngCore};
 
+pub mod adversary;
+pub mod daily;
 mod data;
+pub mod file;
+pub mod hard;
 pub mod hash;
+pub mod list;
+pub mod matrix;
+pub mod score;
+pub mod share;
+pub mod solver;
+pub mod suggest;
 
-/// Returns a random word from [`WORDS`].
+/// Returns a random answer from [`WORDS`]. Never returns one of the [`GUESSES`].
 pub fn pick_random_word<R: Rng + ?Sized>(random_state: &mut R) -> Word {
     **WORDS.choose(random_state).expect("WORDS has no elements")
 }
 
+/// Returns whether `word` is an allowed guess, i.e. whether it is in [`WORDS`] or [`GUESSES`].
+/// Some guesses the official game accepts are not, see [`GUESSES`].
 pub fn check(word: &Word) -> bool {
     data::check(word)
 }
 
-/// Returns a `&[u8; 5]` as a `&str`.
-pub fn to_str(word: &Word) -> &str {
+/// Returns whether `word` is in [`WORDS`], i.e. whether it can be picked as an answer.
+pub fn is_answer(word: &Word) -> bool {
+    data::is_answer(word)
+}
+
+/// The longest supported word length.
+pub const MAX_LEN: usize = 10;
+
+/// The number of guesses a player gets unless configured otherwise.
+pub const DEFAULT_TURN_LIMIT: usize = 6;
+
+/// The highest supported turn limit.
+pub const MAX_TURN_LIMIT: usize = 20;
+
+/// Returns a `&[u8; N]` as a `&str`.
+pub fn to_str<const N: usize>(word: &Word<N>) -> &str {
     unsafe { std::str::from_utf8_unchecked(word) }
 }
 
@@ -43,9 +72,20 @@ impl RngCore for Rand {
 }
 
 impl Rand {
-    // TODO: let users pass in a seed
+    /// Creates a random state from `seed`. The same seed always yields the same numbers.
     pub fn new(seed: u64) -> Self {
-        Self(seed)
+        // XorShift gets stuck on a state of 0
+        const ZERO_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+        Self(if seed == 0 { ZERO_SEED } else { seed })
+    }
+
+    /// Returns a seed derived from the current time.
+    pub fn random_seed() -> u64 {
+        use std::time::SystemTime;
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("time went backwards")
+            .as_nanos() as u64
     }
 
     pub fn sample(&mut self) -> u64 {
@@ -61,12 +101,9 @@ impl Rand {
 
 impl Default for Rand {
     /// Seeds the random state with the current time.
+    ///
+    /// Use [`daily_word`] to have everyone play the same word.
     fn default() -> Self {
-        use std::time::SystemTime;
-        let seed = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("time went backwards")
-            .as_nanos() as u64;
-        Self(seed)
+        Self::new(Self::random_seed())
     }
 }
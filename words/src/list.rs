@@ -0,0 +1,281 @@
+//! Word lists loaded at runtime.
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::daily;
+use crate::hash::WordHashBuilder;
+use crate::{Word, WORDS};
+
+/// A list of answers of `N` letters each, either the built-in [`WORDS`] or one supplied by the
+/// user.
+///
+/// Lists are read one word per line. Surrounding whitespace is ignored, as are empty lines and
+/// lines starting with `#`. Words are converted to uppercase.
+#[derive(Clone, Debug)]
+pub struct WordList<const N: usize = 5> {
+    words: Vec<Word<N>>,
+    set: HashSet<Word<N>, WordHashBuilder>,
+}
+
+impl<const N: usize> WordList<N> {
+    /// Reads a word list from the file at `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let file = File::open(path)?;
+        Self::from_reader(BufReader::new(file))
+    }
+
+    /// Reads a word list from `reader`.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, LoadError> {
+        let mut list = Self::with_capacity(0);
+        // the line each word was read from, to report duplicates
+        let mut lines = Vec::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line?;
+            let entry = line.trim();
+            if entry.is_empty() || entry.starts_with('#') {
+                continue;
+            }
+
+            if let Some(c) = entry.chars().find(|c| !c.is_ascii_alphabetic()) {
+                return Err(LoadError::Invalid {
+                    line: line_no,
+                    kind: InvalidWord::NotALetter(c),
+                });
+            }
+            let Ok(mut word) = Word::<N>::try_from(entry.as_bytes()) else {
+                return Err(LoadError::Invalid {
+                    line: line_no,
+                    kind: InvalidWord::Length {
+                        expected: N,
+                        found: entry.len(),
+                    },
+                });
+            };
+            word.make_ascii_uppercase();
+
+            if !list.set.insert(word) {
+                let first = list.words.iter().position(|w| *w == word).unwrap();
+                return Err(LoadError::Invalid {
+                    line: line_no,
+                    kind: InvalidWord::Duplicate(lines[first]),
+                });
+            }
+            list.words.push(word);
+            lines.push(line_no);
+        }
+
+        if list.is_empty() {
+            return Err(LoadError::Empty);
+        }
+
+        Ok(list)
+    }
+
+    /// Returns the built-in [`WORDS`], in the order of the daily puzzles, if `N` is 5.
+    /// There are no built-in lists for other lengths.
+    pub fn builtin() -> Option<Self> {
+        if N != 5 {
+            return None;
+        }
+
+        let mut list = Self::with_capacity(WORDS.len());
+        for word in daily::daily_order() {
+            let word = Word::<N>::try_from(&word[..]).unwrap();
+            list.set.insert(word);
+            list.words.push(word);
+        }
+        Some(list)
+    }
+
+    /// Creates a list of `words`, which must be in uppercase, skipping duplicates. Returns `None`
+    /// if there are no words.
+    pub fn from_words(words: impl IntoIterator<Item = Word<N>>) -> Option<Self> {
+        let mut list = Self::with_capacity(0);
+        for word in words {
+            if list.set.insert(word) {
+                list.words.push(word);
+            }
+        }
+        (!list.is_empty()).then_some(list)
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(capacity),
+            set: HashSet::with_capacity_and_hasher(capacity, WordHashBuilder),
+        }
+    }
+
+    /// Returns the words in the order they were read in.
+    pub fn words(&self) -> &[Word<N>] {
+        &self.words
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn contains(&self, word: &Word<N>) -> bool {
+        self.set.contains(word)
+    }
+
+    /// Returns the answer to puzzle number `puzzle`, see [`daily`].
+    pub fn daily(&self, puzzle: u32) -> Word<N> {
+        self.words[puzzle as usize % self.words.len()]
+    }
+
+    /// Returns a random word from this list.
+    pub fn pick_random<R: Rng + ?Sized>(&self, random_state: &mut R) -> Word<N> {
+        *self
+            .words
+            .choose(random_state)
+            .expect("word lists are never empty")
+    }
+}
+
+impl Default for WordList {
+    /// Returns the built-in [`WORDS`].
+    fn default() -> Self {
+        Self::builtin().unwrap()
+    }
+}
+
+/// The error returned when loading a [`WordList`] fails.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    /// The entry on line `line` (counting from 1) is not a valid word.
+    Invalid {
+        line: usize,
+        kind: InvalidWord,
+    },
+    /// The list does not contain any words.
+    Empty,
+}
+
+/// Why an entry of a word list was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvalidWord {
+    /// The entry contains a character that is not an ASCII letter.
+    NotALetter(char),
+    /// The entry does not have the right number of letters.
+    Length { expected: usize, found: usize },
+    /// The entry already appeared on the given line.
+    Duplicate(usize),
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read word list: {e}"),
+            Self::Invalid { line, kind } => write!(f, "line {line}: {kind}"),
+            Self::Empty => write!(f, "word list is empty"),
+        }
+    }
+}
+
+impl fmt::Display for InvalidWord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotALetter(c) => write!(f, "expected an ascii letter, found: {c:?}"),
+            Self::Length { expected, found } => {
+                write!(f, "expected {expected} letters, found {found}")
+            }
+            Self::Duplicate(first) => write!(f, "duplicate of line {first}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid(input: &str) -> (usize, InvalidWord) {
+        match WordList::<5>::from_reader(input.as_bytes()) {
+            Err(LoadError::Invalid { line, kind }) => (line, kind),
+            other => panic!("expected invalid word, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let list = WordList::from_reader(&b"# comment\ncigar\r\n\n  REBUT \nsissy"[..]).unwrap();
+        assert_eq!(list.words(), [*b"CIGAR", *b"REBUT", *b"SISSY"]);
+        assert!(list.contains(b"REBUT"));
+        assert!(!list.contains(b"CRANE"));
+
+        assert_eq!(
+            invalid("CIGAR\nDONT\n"),
+            (
+                2,
+                InvalidWord::Length {
+                    expected: 5,
+                    found: 4
+                }
+            )
+        );
+        assert_eq!(invalid("CIGAR\nCAFÉS\n"), (2, InvalidWord::NotALetter('É')));
+        assert_eq!(invalid("CI GAR\n"), (1, InvalidWord::NotALetter(' ')));
+        assert_eq!(
+            invalid("CIGAR\n\nREBUT\ncigar\n"),
+            (4, InvalidWord::Duplicate(1))
+        );
+        assert!(matches!(
+            WordList::<5>::from_reader(&b"\n# nothing here\n"[..]),
+            Err(LoadError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_from_words() {
+        let list = WordList::from_words([*b"CIGAR", *b"REBUT", *b"CIGAR"]).unwrap();
+        assert_eq!(list.words(), [*b"CIGAR", *b"REBUT"]);
+        assert!(list.contains(b"REBUT"));
+        assert!(WordList::<5>::from_words([]).is_none());
+    }
+
+    #[test]
+    fn test_other_lengths() {
+        let list = WordList::<6>::from_reader(&b"planet\nrocket\n"[..]).unwrap();
+        assert_eq!(list.words(), [*b"PLANET", *b"ROCKET"]);
+        assert!(list.contains(b"ROCKET"));
+        assert!(WordList::<4>::from_reader(&b"planet\n"[..]).is_err());
+        assert!(WordList::<6>::builtin().is_none());
+    }
+
+    #[test]
+    fn test_shipped_list() {
+        let list = WordList::from_path(concat!(env!("CARGO_MANIFEST_DIR"), "/words.txt")).unwrap();
+        let default = WordList::default();
+        assert_eq!(list.words(), default.words());
+        assert_eq!(list.len(), WORDS.len());
+        assert!(WORDS.iter().all(|w| list.contains(w)));
+    }
+}
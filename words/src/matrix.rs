@@ -0,0 +1,375 @@
+//! Precomputed scores for every pair of a guess and an answer.
+//!
+//! Solvers score the same pairs over and over. A [`ScoreMatrix`] stores each score as a single
+//! byte, so that scoring becomes a lookup. Building the matrix for the built-in lists takes a
+//! while, so it can be cached on disk, see [`ScoreMatrix::cached`].
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use crate::file;
+use crate::hash::WordHashBuilder;
+use crate::score::{score, Score};
+use crate::{Word, GUESSES, WORDS};
+
+/// The first bytes of a cached matrix, followed by a format version.
+const MAGIC: &[u8; 4] = b"WSM\x01";
+
+/// The scores of a list of guesses against a list of answers.
+#[derive(Clone, Debug)]
+pub struct ScoreMatrix<const N: usize = 5> {
+    guesses: Vec<Word<N>>,
+    answers: Vec<Word<N>>,
+    guess_index: HashMap<Word<N>, u32, WordHashBuilder>,
+    answer_index: HashMap<Word<N>, u32, WordHashBuilder>,
+    /// The bits of each score, one row of `answers.len()` bytes per guess.
+    scores: Vec<u8>,
+}
+
+impl ScoreMatrix {
+    /// Returns the matrix of all of [`WORDS`] and [`GUESSES`] against [`WORDS`], building it on
+    /// first use.
+    pub fn builtin() -> Arc<Self> {
+        static MATRIX: OnceLock<Arc<ScoreMatrix>> = OnceLock::new();
+        let matrix = MATRIX.get_or_init(|| {
+            let (guesses, answers) = builtin_words();
+            Arc::new(Self::build(&guesses, &answers).unwrap())
+        });
+        Arc::clone(matrix)
+    }
+
+    /// Like [`ScoreMatrix::builtin`], but cached at `path`, see [`ScoreMatrix::cached`].
+    pub fn builtin_cached(path: impl AsRef<Path>) -> Self {
+        let (guesses, answers) = builtin_words();
+        Self::cached(path, &guesses, &answers).unwrap()
+    }
+}
+
+/// Returns the guesses and answers of [`ScoreMatrix::builtin`].
+fn builtin_words() -> (Vec<Word>, Vec<Word>) {
+    let answers: Vec<_> = WORDS.iter().map(|&&word| word).collect();
+    let guesses = answers
+        .iter()
+        .copied()
+        .chain(GUESSES.iter().map(|&&word| word))
+        .collect();
+    (guesses, answers)
+}
+
+impl<const N: usize> ScoreMatrix<N> {
+    /// Scores every one of `guesses` against every one of `answers`.
+    ///
+    /// Returns `None` if `N` is more than 5, because scores of longer words don't fit in a byte.
+    pub fn build(guesses: &[Word<N>], answers: &[Word<N>]) -> Option<Self> {
+        if Score::<N>::COUNT > 1 << u8::BITS {
+            return None;
+        }
+
+        let mut scores = Vec::with_capacity(guesses.len() * answers.len());
+        for guess in guesses {
+            for answer in answers {
+                scores.push(score(answer, guess).to_bits() as u8);
+            }
+        }
+        Some(Self::from_parts(guesses.to_vec(), answers.to_vec(), scores))
+    }
+
+    fn from_parts(guesses: Vec<Word<N>>, answers: Vec<Word<N>>, scores: Vec<u8>) -> Self {
+        debug_assert_eq!(scores.len(), guesses.len() * answers.len());
+        Self {
+            guess_index: index(&guesses),
+            answer_index: index(&answers),
+            guesses,
+            answers,
+            scores,
+        }
+    }
+
+    /// Loads the matrix cached at `path` if it was built from `guesses` and `answers`, and builds
+    /// and caches it otherwise.
+    ///
+    /// The cache is only an optimization: if it can't be read or written, the matrix is built in
+    /// memory. Returns `None` if `N` is more than 5, see [`ScoreMatrix::build`].
+    pub fn cached(
+        path: impl AsRef<Path>,
+        guesses: &[Word<N>],
+        answers: &[Word<N>],
+    ) -> Option<Self> {
+        let path = path.as_ref();
+        match Self::load(path) {
+            Ok(matrix) if matrix.guesses == guesses && matrix.answers == answers => {
+                return Some(matrix)
+            }
+            _ => {}
+        }
+
+        let matrix = Self::build(guesses, answers)?;
+        // a stale or missing cache is rebuilt next time
+        let _ = matrix.save(path);
+        Some(matrix)
+    }
+
+    /// Reads a matrix written by [`ScoreMatrix::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a matrix written by [`ScoreMatrix::write`], validating its checksum.
+    pub fn read(mut reader: impl Read) -> Result<Self, LoadError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        let Some((data, checksum)) = data.split_last_chunk::<8>() else {
+            return Err(LoadError::Truncated);
+        };
+        if checksum != &fnv1a(data).to_le_bytes() {
+            return Err(LoadError::Checksum);
+        }
+
+        let mut data = data;
+        if take(&mut data, MAGIC.len())? != MAGIC {
+            return Err(LoadError::Format);
+        }
+        if take(&mut data, 1)?[0] as usize != N {
+            return Err(LoadError::Format);
+        }
+        let guess_count = take_u32(&mut data)? as usize;
+        let answer_count = take_u32(&mut data)? as usize;
+        let guesses = take_words(&mut data, guess_count)?;
+        let answers = take_words(&mut data, answer_count)?;
+        let scores = take(&mut data, guess_count * answer_count)?;
+        if !data.is_empty()
+            || scores
+                .iter()
+                .any(|&bits| bits as usize >= Score::<N>::COUNT)
+        {
+            return Err(LoadError::Format);
+        }
+
+        Ok(Self::from_parts(guesses, answers, scores.to_vec()))
+    }
+
+    /// Writes this matrix to `path`. The file is replaced at once, so readers never see a partly
+    /// written matrix.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        file::write_atomic(path.as_ref(), |w| self.write(w))
+    }
+
+    /// Writes this matrix, followed by a checksum.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        let mut data = Vec::with_capacity(self.scores.len() + 64);
+        data.extend_from_slice(MAGIC);
+        data.push(N as u8);
+        data.extend_from_slice(&(self.guesses.len() as u32).to_le_bytes());
+        data.extend_from_slice(&(self.answers.len() as u32).to_le_bytes());
+        for word in self.guesses.iter().chain(&self.answers) {
+            data.extend_from_slice(word);
+        }
+        data.extend_from_slice(&self.scores);
+        data.extend_from_slice(&fnv1a(&data).to_le_bytes());
+        writer.write_all(&data)
+    }
+
+    /// Returns the guesses, in the order of the rows.
+    pub fn guesses(&self) -> &[Word<N>] {
+        &self.guesses
+    }
+
+    /// Returns the answers, in the order of the columns.
+    pub fn answers(&self) -> &[Word<N>] {
+        &self.answers
+    }
+
+    /// Returns the row of `guess`, if it is one of the guesses.
+    pub fn guess_index(&self, guess: &Word<N>) -> Option<usize> {
+        self.guess_index.get(guess).map(|&i| i as usize)
+    }
+
+    /// Returns the column of `answer`, if it is one of the answers.
+    pub fn answer_index(&self, answer: &Word<N>) -> Option<usize> {
+        self.answer_index.get(answer).map(|&i| i as usize)
+    }
+
+    /// Returns the score of the guess in row `guess` against the answer in column `answer`.
+    /// Panics if either is out of bounds.
+    pub fn get(&self, guess: usize, answer: usize) -> Score<N> {
+        assert!(answer < self.answers.len(), "answer index out of bounds");
+        let bits = self.scores[guess * self.answers.len() + answer];
+        Score::from_bits(bits as u16).unwrap()
+    }
+
+    /// Returns the bits of the scores of the guess in row `guess`, see [`Score::to_bits`], one per
+    /// answer in the order of the columns. Panics if `guess` is out of bounds.
+    pub fn row(&self, guess: usize) -> &[u8] {
+        let len = self.answers.len();
+        &self.scores[guess * len..][..len]
+    }
+
+    /// Returns the same as [`score`], looking it up if both words are in the matrix and
+    /// computing it otherwise.
+    pub fn score(&self, word: &Word<N>, guess: &Word<N>) -> Score<N> {
+        match (self.guess_index(guess), self.answer_index(word)) {
+            (Some(guess), Some(answer)) => self.get(guess, answer),
+            _ => score(word, guess),
+        }
+    }
+}
+
+fn index<const N: usize>(words: &[Word<N>]) -> HashMap<Word<N>, u32, WordHashBuilder> {
+    let mut index = HashMap::with_capacity_and_hasher(words.len(), WordHashBuilder);
+    for (i, word) in words.iter().enumerate() {
+        index.entry(*word).or_insert(i as u32);
+    }
+    index
+}
+
+/// The 64 bit FNV-1a hash of `data`.
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Splits the first `len` bytes off `data`.
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Result<&'a [u8], LoadError> {
+    if data.len() < len {
+        return Err(LoadError::Truncated);
+    }
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Ok(head)
+}
+
+fn take_u32(data: &mut &[u8]) -> Result<u32, LoadError> {
+    let bytes = take(data, 4)?;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+fn take_words<const N: usize>(data: &mut &[u8], count: usize) -> Result<Vec<Word<N>>, LoadError> {
+    let bytes = take(data, count * N)?;
+    if !bytes.iter().all(u8::is_ascii_uppercase) {
+        return Err(LoadError::Format);
+    }
+    Ok(bytes
+        .chunks_exact(N)
+        .map(|word| word.try_into().unwrap())
+        .collect())
+}
+
+/// The error returned when reading a [`ScoreMatrix`] fails.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    /// The data ends too early.
+    Truncated,
+    /// The data doesn't match its checksum.
+    Checksum,
+    /// The data is not a matrix of words of the expected length.
+    Format,
+}
+
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoadError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read score matrix: {e}"),
+            Self::Truncated => write!(f, "score matrix is truncated"),
+            Self::Checksum => write!(f, "score matrix is corrupted"),
+            Self::Format => write!(f, "not a score matrix for this word length"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn matrix() -> ScoreMatrix {
+        ScoreMatrix::build(&[*b"CRANE", *b"REBUS"], &[*b"REBUS", *b"CIGAR", *b"SISSY"]).unwrap()
+    }
+
+    #[test]
+    fn test_score() {
+        let matrix = matrix();
+        for guess in [b"CRANE", b"REBUS", b"SLOTH"] {
+            for word in [b"REBUS", b"CIGAR", b"SISSY", b"HUMPH"] {
+                assert_eq!(matrix.score(word, guess), score(word, guess));
+            }
+        }
+        assert_eq!(matrix.get(1, 0), Score::PERFECT);
+        assert_eq!(matrix.guess_index(b"REBUS"), Some(1));
+        assert_eq!(matrix.answer_index(b"CRANE"), None);
+        assert!(ScoreMatrix::<6>::build(&[*b"CRANES"], &[*b"CRANES"]).is_none());
+    }
+
+    #[test]
+    fn test_read_write() {
+        let matrix = matrix();
+        let mut data = Vec::new();
+        matrix.write(&mut data).unwrap();
+
+        let read = ScoreMatrix::read(&data[..]).unwrap();
+        assert_eq!(read.guesses(), matrix.guesses());
+        assert_eq!(read.answers(), matrix.answers());
+        assert_eq!(read.scores, matrix.scores);
+
+        assert!(matches!(
+            ScoreMatrix::<4>::read(&data[..]),
+            Err(LoadError::Format)
+        ));
+        let mut corrupted = data.clone();
+        corrupted[20] ^= 1;
+        assert!(matches!(
+            ScoreMatrix::<5>::read(&corrupted[..]),
+            Err(LoadError::Checksum)
+        ));
+        assert!(matches!(
+            ScoreMatrix::<5>::read(&data[..4]),
+            Err(LoadError::Truncated)
+        ));
+    }
+
+    #[test]
+    fn test_cached() {
+        let dir = std::env::temp_dir().join(format!("words-matrix-{}", std::process::id()));
+        let path = dir.join("scores");
+        let guesses = [*b"CRANE"];
+
+        let built = ScoreMatrix::cached(&path, &guesses, &[*b"REBUS"]).unwrap();
+        assert!(path.exists());
+        let loaded = ScoreMatrix::cached(&path, &guesses, &[*b"REBUS"]).unwrap();
+        assert_eq!(loaded.scores, built.scores);
+
+        // a cache of other words is replaced
+        let other = ScoreMatrix::cached(&path, &guesses, &[*b"CIGAR", *b"REBUS"]).unwrap();
+        assert_eq!(other.answers(), [*b"CIGAR", *b"REBUS"]);
+        assert_eq!(ScoreMatrix::load(&path).unwrap().answers(), other.answers());
+
+        // so is a corrupted one
+        fs::write(&path, b"garbage").unwrap();
+        let rebuilt = ScoreMatrix::cached(&path, &guesses, &[*b"REBUS"]).unwrap();
+        assert_eq!(rebuilt.scores, built.scores);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,373 @@
+//! Grading guesses against an answer.
+//!
+//! A [`Score`] packs the [`LetterScore`] of every letter of a guess into a single integer, using
+//! one base-3 digit per letter. This works out because log2(3^10) < 16, so words of up to
+//! [`MAX_LEN`](crate::MAX_LEN) letters fit into a `u16`.
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Word, MAX_LEN};
+
+/// The score of a single letter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum LetterScore {
+    /// A letter that is not in the word at all.
+    #[default]
+    Wrong = 0,
+    /// A letter that is in the word but at a different spot.
+    InWord = 1,
+    /// A letter both in the word and at the right spot.
+    Right = 2,
+}
+
+impl LetterScore {
+    pub const ALL: [Self; 3] = [Self::Wrong, Self::InWord, Self::Right];
+
+    // this is obviously unfortunate compared to just assigning the variants directly
+    // but should the variants ever need special values this will come in handy.
+    const fn variant(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the character this score is displayed as: `b`(lack), `y`(ellow) or `g`(reen).
+    pub const fn to_char(self) -> char {
+        match self {
+            Self::Wrong => 'b',
+            Self::InWord => 'y',
+            Self::Right => 'g',
+        }
+    }
+
+    /// The inverse of [`to_char`](Self::to_char). Ignores case.
+    pub const fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_lowercase() {
+            'b' => Some(Self::Wrong),
+            'y' => Some(Self::InWord),
+            'g' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LetterScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// The score of a whole guess.
+///
+/// Displays as one [`LetterScore`] character per letter, e.g. `gybbg`, and can be parsed back from
+/// that representation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Score<const N: usize = 5>(u16);
+
+impl<const N: usize> Score<N> {
+    /// The number of letters in a score.
+    pub const LEN: usize = {
+        assert!(N > 0 && N <= MAX_LEN, "unsupported word length");
+        N
+    };
+
+    /// The number of distinct scores.
+    pub const COUNT: usize = 3usize.pow(Self::LEN as u32);
+
+    pub const PERFECT: Self = Score(Self::COUNT as u16 - 1);
+
+    pub fn is_win(self) -> bool {
+        self == Self::PERFECT
+    }
+
+    /// Returns the packed representation of this score. Always less than [`Score::COUNT`].
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Creates a score from its packed representation.
+    /// Returns `None` if `bits` is not less than [`Score::COUNT`].
+    pub const fn from_bits(bits: u16) -> Option<Self> {
+        if (bits as usize) < Self::COUNT {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Sets the score of the letter at `at`. Panics if `at` is out of bounds.
+    pub fn set(&mut self, at: usize, score: LetterScore) {
+        let pos = Self::pos(at);
+        let old = self.get(at);
+        self.0 = self.0 - old.variant() as u16 * pos + score.variant() as u16 * pos;
+    }
+
+    /// Returns the score of the letter at `at`. Panics if `at` is out of bounds.
+    pub fn get(self, at: usize) -> LetterScore {
+        LetterScore::ALL[(self.0 / Self::pos(at)) as usize % 3]
+    }
+
+    /// Returns an iterator over the scores of each letter.
+    pub fn iter(self) -> Iter<N> {
+        Iter {
+            score: self,
+            at: 0..Self::LEN,
+        }
+    }
+
+    fn pos(at: usize) -> u16 {
+        assert!(at < Self::LEN, "index {at} out of bounds");
+        3u16.pow(at as u32)
+    }
+}
+
+impl<const N: usize> From<[LetterScore; N]> for Score<N> {
+    fn from(value: [LetterScore; N]) -> Self {
+        let mut compressed = 0;
+        for s in value.iter().rev() {
+            compressed *= 3;
+            compressed += s.variant() as u16;
+        }
+        Score(compressed)
+    }
+}
+
+impl<const N: usize> From<Score<N>> for [LetterScore; N] {
+    fn from(value: Score<N>) -> Self {
+        std::array::from_fn(|i| value.get(i))
+    }
+}
+
+impl<const N: usize> IntoIterator for Score<N> {
+    type Item = LetterScore;
+    type IntoIter = Iter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the [`LetterScore`]s of a [`Score`].
+#[derive(Clone, Debug)]
+pub struct Iter<const N: usize = 5> {
+    score: Score<N>,
+    at: std::ops::Range<usize>,
+}
+
+impl<const N: usize> Iterator for Iter<N> {
+    type Item = LetterScore;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.at.next().map(|at| self.score.get(at))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.at.size_hint()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for Iter<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.at.next_back().map(|at| self.score.get(at))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Iter<N> {}
+
+impl<const N: usize> fmt::Display for Score<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for letter in *self {
+            write!(f, "{letter}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> FromStr for Score<N> {
+    type Err = ParseScoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let len = s.chars().count();
+        if len != Self::LEN {
+            return Err(ParseScoreError::Length {
+                expected: N,
+                found: len,
+            });
+        }
+
+        let mut score = Score::default();
+        for (i, c) in s.chars().enumerate() {
+            let letter = LetterScore::from_char(c).ok_or(ParseScoreError::InvalidChar(c))?;
+            score.set(i, letter);
+        }
+        Ok(score)
+    }
+}
+
+/// The error returned when parsing a [`Score`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseScoreError {
+    /// The input did not have one character per letter.
+    Length { expected: usize, found: usize },
+    /// The input contained a character other than `b`, `y` or `g`.
+    InvalidChar(char),
+}
+
+impl Error for ParseScoreError {}
+
+impl fmt::Display for ParseScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length { expected, found } => {
+                write!(f, "score must have {expected} characters, found {found}")
+            }
+            Self::InvalidChar(c) => {
+                write!(f, "expected one of 'b', 'y' or 'g', found: {c:?}")
+            }
+        }
+    }
+}
+
+/// Grades `guess` against the answer `word`.
+pub fn score<const N: usize>(word: &Word<N>, guess: &Word<N>) -> Score<N> {
+    if word == guess {
+        return Score::PERFECT;
+    }
+
+    // invalid ascii byte to use as a placeholder
+    const SENTINEL: u8 = u8::MAX;
+    let mut score = Score::default();
+    let mut word = *word;
+
+    // find correct letters
+    for (i, (w, g)) in word.iter_mut().zip(guess).enumerate() {
+        if w == g {
+            score.set(i, LetterScore::Right);
+            *w = SENTINEL;
+        }
+    }
+
+    for (i, g) in guess.iter().enumerate() {
+        if score.get(i) == LetterScore::Right {
+            continue;
+        }
+        if let Some(w) = word.iter_mut().find(|w| *w == g) {
+            score.set(i, LetterScore::InWord);
+            *w = SENTINEL;
+        }
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        use LetterScore::*;
+        let table = [
+            (
+                b"words",
+                b"birds",
+                [Wrong, Wrong, Right, Right, Right].into(),
+            ),
+            (
+                b"tests",
+                b"stabs",
+                [InWord, InWord, Wrong, Wrong, Right].into(),
+            ),
+            (b"cargo", b"gocar", [InWord; 5].into()),
+            (b"cargo", b"cargo", [Right; 5].into()),
+            (
+                b"stark",
+                b"lossy",
+                [Wrong, Wrong, InWord, Wrong, Wrong].into(),
+            ),
+            (
+                b"liege",
+                b"liens",
+                [Right, Right, Right, Wrong, Wrong].into(),
+            ),
+            (
+                b"liege",
+                b"litre",
+                [Right, Right, Wrong, Wrong, Right].into(),
+            ),
+            (
+                b"abcde",
+                b"edcba",
+                [InWord, InWord, Right, InWord, InWord].into(),
+            ),
+            (
+                b"abcde",
+                b"ccccc",
+                [Wrong, Wrong, Right, Wrong, Wrong].into(),
+            ),
+            (
+                b"abcde",
+                b"ccxxx",
+                [InWord, Wrong, Wrong, Wrong, Wrong].into(),
+            ),
+        ];
+
+        for (w, g, expected) in table {
+            let got = score(w, g);
+            assert_eq!(got, expected);
+            assert_eq!(got, Score::from(<[LetterScore; 5]>::from(got)));
+        }
+    }
+
+    #[test]
+    fn test_display_parse() {
+        for bits in 0..Score::<5>::COUNT as u16 {
+            let score = Score::<5>::from_bits(bits).unwrap();
+            let s = score.to_string();
+            assert_eq!(s.parse::<Score>(), Ok(score), "{s}");
+        }
+        assert_eq!(Score::<5>::from_bits(Score::<5>::COUNT as u16), None);
+
+        assert_eq!(score(b"CRANE", b"CRATE").to_string(), "gggbg");
+        assert_eq!("GYBBG".parse::<Score>().unwrap().to_string(), "gybbg");
+        assert_eq!(
+            "gybb".parse::<Score>(),
+            Err(ParseScoreError::Length {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(
+            "gyxbg".parse::<Score>(),
+            Err(ParseScoreError::InvalidChar('x'))
+        );
+    }
+
+    #[test]
+    fn test_lengths() {
+        use LetterScore::*;
+        assert_eq!(score(b"TEN", b"NET"), [InWord, Right, InWord].into());
+        assert_eq!(
+            score(b"PLANETS", b"PLASTER"),
+            [Right, Right, Right, InWord, InWord, InWord, Wrong].into()
+        );
+        assert_eq!(Score::<10>::PERFECT.to_bits(), 59048);
+        assert!(score(b"AAAAAAAAAA", b"AAAAAAAAAA").is_win());
+        assert_eq!(
+            "ggggggybbb".parse::<Score<10>>().unwrap().to_string(),
+            "ggggggybbb"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut score = Score::<5>::PERFECT;
+        score.set(1, LetterScore::Wrong);
+        score.set(3, LetterScore::InWord);
+        assert_eq!(score.to_string(), "gbgyg");
+        assert_eq!(
+            score.iter().rev().collect::<Vec<_>>()[1],
+            LetterScore::InWord
+        );
+    }
+}
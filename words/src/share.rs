@@ -0,0 +1,173 @@
+//! Spoiler-free summaries of finished games, made of colored squares.
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::score::{LetterScore, Score};
+
+/// The squares a [`ShareGrid`] is drawn with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Theme {
+    /// White squares for wrong letters.
+    Light,
+    /// Black squares for wrong letters.
+    #[default]
+    Dark,
+    /// Orange and blue instead of green and yellow, for colorblind players.
+    HighContrast,
+}
+
+impl Theme {
+    /// Returns the square `score` is drawn as.
+    pub const fn square(self, score: LetterScore) -> char {
+        match (self, score) {
+            (Self::HighContrast, LetterScore::Right) => '🟧',
+            (Self::HighContrast, LetterScore::InWord) => '🟦',
+            (_, LetterScore::Right) => '🟩',
+            (_, LetterScore::InWord) => '🟨',
+            (Self::Light, LetterScore::Wrong) => '⬜',
+            (_, LetterScore::Wrong) => '⬛',
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = ParseThemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "light" => Ok(Self::Light),
+            "dark" => Ok(Self::Dark),
+            "high-contrast" => Ok(Self::HighContrast),
+            _ => Err(ParseThemeError(s.to_string())),
+        }
+    }
+}
+
+/// The error returned when parsing a [`Theme`] fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseThemeError(String);
+
+impl Error for ParseThemeError {}
+
+impl fmt::Display for ParseThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected one of light, dark or high-contrast, found: {:?}",
+            self.0
+        )
+    }
+}
+
+/// The result of a game as players share it, e.g.
+///
+/// ```text
+/// Wordle 196 3/6*
+///
+/// ⬛🟨⬛⬛🟨
+/// ⬛⬛⬛🟩🟩
+/// 🟩🟩🟩🟩🟩
+/// ```
+///
+/// The title line shows the number of guesses, or `X` if the game was lost, and is marked with
+/// `*` for games played in hard mode. Games won with the help of hints say how many, e.g.
+/// `Wordle 196 4/6 (2 hints)`.
+#[derive(Clone, Copy, Debug)]
+pub struct ShareGrid<'a, const N: usize = 5> {
+    /// The name of the game, e.g. `Wordle 196`.
+    pub title: &'a str,
+    /// The scores of the guesses, in order.
+    pub scores: &'a [Score<N>],
+    pub turn_limit: usize,
+    pub hard: bool,
+    /// The number of hints the player asked for.
+    pub hints: usize,
+    pub theme: Theme,
+}
+
+impl<const N: usize> fmt::Display for ShareGrid<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.title)?;
+        match self.scores.last() {
+            Some(score) if score.is_win() => write!(f, "{}", self.scores.len())?,
+            _ => write!(f, "X")?,
+        }
+        write!(f, "/{}", self.turn_limit)?;
+        if self.hard {
+            write!(f, "*")?;
+        }
+        match self.hints {
+            0 => {}
+            1 => write!(f, " (1 hint)")?,
+            n => write!(f, " ({n} hints)")?,
+        }
+        writeln!(f)?;
+
+        for score in self.scores {
+            writeln!(f)?;
+            for letter in *score {
+                write!(f, "{}", self.theme.square(letter))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score::score;
+
+    fn grid(scores: &[Score], hard: bool, theme: Theme) -> String {
+        ShareGrid {
+            title: "Wordle 196",
+            scores,
+            turn_limit: 6,
+            hard,
+            hints: 0,
+            theme,
+        }
+        .to_string()
+    }
+
+    #[test]
+    fn test_share_grid() {
+        let scores = [b"CRANE", b"FOCUS", b"REBUS"].map(|guess| score(b"REBUS", guess));
+        assert_eq!(
+            grid(&scores, false, Theme::Dark),
+            "Wordle 196 3/6\n\n⬛🟨⬛⬛🟨\n⬛⬛⬛🟩🟩\n🟩🟩🟩🟩🟩"
+        );
+        assert_eq!(
+            grid(&scores, true, Theme::Light),
+            "Wordle 196 3/6*\n\n⬜🟨⬜⬜🟨\n⬜⬜⬜🟩🟩\n🟩🟩🟩🟩🟩"
+        );
+        assert_eq!(
+            grid(&scores[..1], false, Theme::HighContrast),
+            "Wordle 196 X/6\n\n⬛🟦⬛⬛🟦"
+        );
+        assert_eq!(grid(&[], false, Theme::Dark), "Wordle 196 X/6\n");
+
+        let hinted = |hints| ShareGrid {
+            title: "Wordle 196",
+            scores: &scores[2..],
+            turn_limit: 6,
+            hard: true,
+            hints,
+            theme: Theme::Dark,
+        };
+        assert_eq!(
+            hinted(1).to_string(),
+            "Wordle 196 1/6* (1 hint)\n\n🟩🟩🟩🟩🟩"
+        );
+        assert!(hinted(3)
+            .to_string()
+            .starts_with("Wordle 196 1/6* (3 hints)\n"));
+    }
+
+    #[test]
+    fn test_parse_theme() {
+        assert_eq!("high-contrast".parse(), Ok(Theme::HighContrast));
+        assert!("blue".parse::<Theme>().is_err());
+    }
+}
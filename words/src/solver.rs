@@ -0,0 +1,269 @@
+//! Ranking guesses by how much they are expected to reveal about the answer.
+//!
+//! A guess splits the remaining candidates into partitions, one per [`Score`] it could get. The
+//! more evenly it splits them, the fewer candidates are expected to remain afterwards. This is
+//! measured by the entropy of the partition sizes: a guess with an entropy of `b` bits is
+//! expected to narrow the candidates down by a factor of `2^b`.
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::hash::WordHashBuilder;
+use crate::matrix::ScoreMatrix;
+use crate::score::{score, Score};
+use crate::{Word, GUESSES, WORDS};
+
+/// Tracks the answers still possible given the feedback so far, and suggests guesses.
+#[derive(Clone, Debug)]
+pub struct Solver<const N: usize = 5> {
+    candidates: Vec<Word<N>>,
+    guesses: Vec<Word<N>>,
+    matrix: Option<Arc<ScoreMatrix<N>>>,
+    /// The column of each candidate in the matrix, if all of them are in it.
+    columns: Option<Vec<usize>>,
+}
+
+impl Solver {
+    /// Creates a solver for the built-in lists: any of [`WORDS`] can be the answer, and any of
+    /// [`WORDS`] and [`GUESSES`] can be guessed.
+    pub fn new() -> Self {
+        let candidates: Vec<_> = WORDS.iter().map(|&&word| word).collect();
+        let guesses = candidates
+            .iter()
+            .copied()
+            .chain(GUESSES.iter().map(|&&word| word))
+            .collect();
+        Self::with_words(candidates, guesses)
+    }
+
+    /// Creates a solver for the built-in lists and narrows it down by every guess of `history`.
+    pub fn from_history(history: &[(Word, Score)]) -> Self {
+        let mut solver = Self::new();
+        for (guess, score) in history {
+            solver.record(guess, *score);
+        }
+        solver
+    }
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Solver<N> {
+    /// Creates a solver where any of `candidates` can be the answer and any of `guesses` can be
+    /// guessed. Candidates are always allowed as guesses, even if they aren't in `guesses`.
+    pub fn with_words(candidates: Vec<Word<N>>, mut guesses: Vec<Word<N>>) -> Self {
+        let mut allowed = HashSet::with_hasher(WordHashBuilder);
+        allowed.extend(guesses.iter().copied());
+        for candidate in &candidates {
+            if allowed.insert(*candidate) {
+                guesses.push(*candidate);
+            }
+        }
+        Self {
+            candidates,
+            guesses,
+            matrix: None,
+            columns: None,
+        }
+    }
+
+    /// Looks scores up in `matrix` instead of computing them, which makes ranking guesses much
+    /// faster. Words that are missing from the matrix are still scored on the fly.
+    pub fn with_matrix(mut self, matrix: Arc<ScoreMatrix<N>>) -> Self {
+        self.columns = self
+            .candidates
+            .iter()
+            .map(|candidate| matrix.answer_index(candidate))
+            .collect();
+        self.matrix = Some(matrix);
+        self
+    }
+
+    /// Removes the candidates that wouldn't have scored `guess` as `score`.
+    pub fn record(&mut self, guess: &Word<N>, score: Score<N>) {
+        let keep: Vec<_> = self
+            .score_bits(guess)
+            .into_iter()
+            .map(|bits| bits == score.to_bits())
+            .collect();
+        let mut kept = keep.iter();
+        self.candidates.retain(|_| *kept.next().unwrap());
+        if let Some(columns) = &mut self.columns {
+            let mut kept = keep.iter();
+            columns.retain(|_| *kept.next().unwrap());
+        }
+    }
+
+    /// Returns the answers that are still possible, in the order they were given.
+    pub fn candidates(&self) -> &[Word<N>] {
+        &self.candidates
+    }
+
+    /// Returns the entropy of `guess` in bits, see the [module docs](self).
+    pub fn entropy(&self, guess: &Word<N>) -> f64 {
+        let total = self.candidates.len() as f64;
+        self.partition(guess)
+            .into_iter()
+            .filter(|&count| count > 0)
+            .map(|count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+
+    /// Returns the number of candidates expected to remain after the best guess, the first of
+    /// [`Solver::best_guesses`]. Returns 0 if there are no candidates.
+    pub fn expected_remaining(&self) -> f64 {
+        match self.best_guesses(1).first() {
+            Some((guess, _)) => self.expected_remaining_after(guess),
+            None => 0.0,
+        }
+    }
+
+    /// Returns the number of candidates expected to remain after guessing `guess`.
+    pub fn expected_remaining_after(&self, guess: &Word<N>) -> f64 {
+        let total = self.candidates.len() as f64;
+        if total == 0.0 {
+            return 0.0;
+        }
+        // a candidate ends up in a partition of `count` words with a probability of `count / total`
+        let sum: usize = self
+            .partition(guess)
+            .into_iter()
+            .map(|count| count * count)
+            .sum();
+        sum as f64 / total
+    }
+
+    /// Returns the `n` guesses with the highest entropy and their entropy, best first.
+    ///
+    /// Among guesses with the same entropy, candidates come first since they might win right
+    /// away.
+    pub fn best_guesses(&self, n: usize) -> Vec<(Word<N>, f64)> {
+        let mut candidates = HashSet::with_hasher(WordHashBuilder);
+        candidates.extend(self.candidates.iter().copied());
+        let mut ranked: Vec<_> = self
+            .guesses
+            .iter()
+            .map(|guess| {
+                let is_candidate = candidates.contains(guess);
+                (*guess, self.entropy(guess), is_candidate)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.2.cmp(&a.2)));
+        ranked
+            .into_iter()
+            .take(n)
+            .map(|(guess, entropy, _)| (guess, entropy))
+            .collect()
+    }
+
+    /// Returns the bits of the score of `guess` against each candidate, see [`Score::to_bits`].
+    /// Reads them from a row of the matrix if it has `guess` and every candidate.
+    fn score_bits(&self, guess: &Word<N>) -> Vec<u16> {
+        if let (Some(matrix), Some(columns)) = (&self.matrix, &self.columns) {
+            if let Some(row) = matrix.guess_index(guess) {
+                let row = matrix.row(row);
+                return columns.iter().map(|&column| row[column] as u16).collect();
+            }
+        }
+        self.candidates
+            .iter()
+            .map(|candidate| match &self.matrix {
+                Some(matrix) => matrix.score(candidate, guess).to_bits(),
+                None => score(candidate, guess).to_bits(),
+            })
+            .collect()
+    }
+
+    /// Returns the number of candidates for each score `guess` could get, indexed by
+    /// [`Score::to_bits`].
+    fn partition(&self, guess: &Word<N>) -> Vec<usize> {
+        let mut counts = vec![0; Score::<N>::COUNT];
+        for bits in self.score_bits(guess) {
+            counts[bits as usize] += 1;
+        }
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_candidates(words: &[&Word]) -> Solver {
+        let words = words.iter().map(|&&word| word).collect();
+        Solver::with_words(words, vec![*b"CRANE"])
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_entropy() {
+        // ybbbb, bybbg and gyybb
+        let solver = with_candidates(&[b"SCOUT", b"HORSE", b"CIGAR"]);
+        assert_close(solver.entropy(b"CRANE"), 3f64.log2());
+        assert_eq!(solver.expected_remaining_after(b"CRANE"), 1.0);
+
+        // gyybb, bbbbb and bbbbb
+        let solver = with_candidates(&[b"CIGAR", b"SISSY", b"HUMPH"]);
+        assert_close(solver.entropy(b"CRANE"), 3f64.log2() - 2.0 / 3.0);
+        assert_eq!(solver.expected_remaining_after(b"CRANE"), 5.0 / 3.0);
+        assert_close(solver.entropy(b"HUMPH"), solver.entropy(b"CRANE"));
+    }
+
+    #[test]
+    fn test_best_guesses() {
+        let solver = with_candidates(&[b"CIGAR", b"SISSY", b"HUMPH"]);
+        // CIGAR and SISSY tell all three apart, and are candidates unlike CRANE
+        let best: Vec<_> = solver.best_guesses(2).into_iter().map(|(w, _)| w).collect();
+        assert_eq!(best, [*b"CIGAR", *b"SISSY"]);
+        assert_close(solver.best_guesses(1)[0].1, 3f64.log2());
+        assert_eq!(solver.best_guesses(10).len(), 4);
+        assert_eq!(solver.expected_remaining(), 1.0);
+        assert_eq!(
+            Solver::<5>::with_words(Vec::new(), Vec::new()).expected_remaining(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_matrix() {
+        let words = [*b"CIGAR", *b"SISSY", *b"HUMPH"];
+        let matrix = ScoreMatrix::build(&words, &words).unwrap();
+        let mut plain = with_candidates(&[b"CIGAR", b"SISSY", b"HUMPH"]);
+        let mut solver = plain.clone().with_matrix(Arc::new(matrix));
+        // CRANE isn't in the matrix
+        assert_eq!(solver.best_guesses(4), plain.best_guesses(4));
+        assert_eq!(solver.expected_remaining_after(b"CRANE"), 5.0 / 3.0);
+
+        for solver in [&mut solver, &mut plain] {
+            solver.record(b"CRANE", score(b"HUMPH", b"CRANE"));
+        }
+        assert_eq!(solver.candidates(), [*b"SISSY", *b"HUMPH"]);
+        assert_eq!(solver.best_guesses(4), plain.best_guesses(4));
+        solver.record(b"SISSY", score(b"HUMPH", b"SISSY"));
+        assert_eq!(solver.candidates(), [*b"HUMPH"]);
+    }
+
+    #[test]
+    fn test_record() {
+        let mut solver = Solver::from_history(&[(*b"CRANE", score(b"REBUS", b"CRANE"))]);
+        assert!(solver.candidates().contains(b"REBUS"));
+        assert!(solver
+            .candidates()
+            .iter()
+            .all(|word| score(word, b"CRANE") == score(b"REBUS", b"CRANE")));
+
+        solver.record(b"REBUS", Score::PERFECT);
+        assert_eq!(solver.candidates(), [*b"REBUS"]);
+        assert_eq!(solver.best_guesses(1), [(*b"REBUS", 0.0)]);
+        assert_eq!(solver.expected_remaining_after(b"REBUS"), 1.0);
+    }
+}
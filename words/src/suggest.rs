@@ -0,0 +1,75 @@
+//! Suggestions for misspelled guesses.
+use crate::Word;
+
+/// The largest edit distance at which [`closest`] still suggests a word.
+pub const MAX_DISTANCE: usize = 2;
+
+/// Returns the number of insertions, deletions, substitutions and swaps of adjacent letters it
+/// takes to turn `a` into `b`.
+pub fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    // optimal string alignment, keeping the last three rows of the table
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cur[j] = cur[j].min(before[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before, &mut prev);
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+/// Returns the word of `candidates` closest to `guess`, or `None` if none is within
+/// [`MAX_DISTANCE`]. Ties go to the word that comes first.
+pub fn closest<'a, const N: usize>(
+    guess: &Word<N>,
+    candidates: impl IntoIterator<Item = &'a Word<N>>,
+) -> Option<&'a Word<N>> {
+    candidates
+        .into_iter()
+        .map(|word| (edit_distance(guess, word), word))
+        .filter(|&(distance, _)| distance <= MAX_DISTANCE)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, word)| word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        let table: [(&[u8], &[u8], usize); 7] = [
+            (b"CRANE", b"CRANE", 0),
+            (b"CRANE", b"CRATE", 1),
+            (b"CRANE", b"CRNAE", 1),
+            (b"CRANE", b"RCANE", 1),
+            (b"CRANE", b"CRAN", 1),
+            (b"CRANE", b"SLOTH", 5),
+            (b"", b"ABC", 3),
+        ];
+        for (a, b, expected) in table {
+            assert_eq!(edit_distance(a, b), expected, "{a:?} {b:?}");
+            assert_eq!(edit_distance(b, a), expected, "{b:?} {a:?}");
+        }
+    }
+
+    #[test]
+    fn test_closest() {
+        let words = [*b"CIGAR", *b"REBUT", *b"CRANE", *b"CRATE"];
+        assert_eq!(closest(b"CRNAE", &words), Some(b"CRANE"));
+        // both are one edit away, the first one wins
+        assert_eq!(closest(b"CRAXE", &words), Some(b"CRANE"));
+        assert_eq!(closest(b"REBTU", &words), Some(b"REBUT"));
+        assert_eq!(closest(b"XXXXX", &words), None);
+    }
+}